@@ -0,0 +1,191 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use serde::Serialize;
+
+/// Allocation activity over some span of code on one thread. `peak` is the
+/// most bytes that were live at once above what was live when it started.
+/// A `realloc` counts as an allocation of only the bytes it grew by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct AllocStats {
+    pub allocs: u64,
+    pub bytes: u64,
+    pub peak: u64,
+}
+
+#[derive(Clone, Copy)]
+struct Counts {
+    allocs: u64,
+    bytes: u64,
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    // Counters are per thread so parallel runs don't pollute each other.
+    // Memory freed on another thread than it was allocated on skews `live`.
+    static COUNTS: Cell<Counts> = const {
+        Cell::new(Counts {
+            allocs: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn record(allocs: u64, allocated: usize, freed: usize) {
+    // `try_with` as the allocator is still used while thread locals are torn down.
+    let _ = COUNTS.try_with(|c| {
+        let mut counts = c.get();
+        counts.allocs += allocs;
+        counts.bytes += allocated as u64;
+        counts.live += allocated as i64 - freed as i64;
+        counts.peak = counts.peak.max(counts.live);
+        c.set(counts);
+    });
+}
+
+/// The system allocator, counting every allocation made through it. Only
+/// installed with the `count-allocs` feature.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(1, layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(1, layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, 0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            let old = layout.size();
+            record(
+                1,
+                new_size.saturating_sub(old),
+                old.saturating_sub(new_size),
+            );
+        }
+        new
+    }
+}
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+pub fn enabled() -> bool {
+    cfg!(feature = "count-allocs")
+}
+
+/// Runs `f`, returning its allocation stats when the counting allocator is
+/// installed. Calls can nest; the outer call's peak includes the inner's.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !enabled() {
+        return (f(), None);
+    }
+    let outer_peak = COUNTS.with(Cell::get).peak;
+    let before = COUNTS.with(|c| {
+        let mut counts = c.get();
+        counts.peak = counts.live;
+        c.set(counts);
+        counts
+    });
+    let out = f();
+    let after = COUNTS.with(|c| {
+        let counts = c.get();
+        c.set(Counts {
+            peak: counts.peak.max(outer_peak),
+            ..counts
+        });
+        counts
+    });
+    let stats = AllocStats {
+        allocs: after.allocs - before.allocs,
+        bytes: after.bytes - before.bytes,
+        peak: (after.peak - before.live).max(0) as u64,
+    };
+    (out, Some(stats))
+}
+
+pub fn fmt_bytes(bytes: u64) -> String {
+    match bytes {
+        b if b < 1 << 10 => format!("{}B", b),
+        b if b < 1 << 20 => format!("{:.1}KiB", b as f64 / 1024.0),
+        b => format!("{:.1}MiB", b as f64 / (1024.0 * 1024.0)),
+    }
+}
+
+impl std::fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {}, peak {}",
+            self.allocs,
+            fmt_bytes(self.bytes),
+            fmt_bytes(self.peak)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test_alloc {
+    use crate::alloc::*;
+
+    #[test]
+    fn test_measure() {
+        let (v, stats) = measure(|| Vec::<u8>::with_capacity(4000));
+        assert_eq!(v.capacity(), 4000);
+        assert_eq!(stats.is_some(), enabled());
+        if let Some(stats) = stats {
+            assert_eq!(stats.allocs, 1);
+            assert_eq!(stats.bytes, 4000);
+            assert_eq!(stats.peak, 4000);
+        }
+        let (_, stats) = measure(|| {
+            let a = vec![0u8; 100];
+            drop(a);
+            vec![0u8; 60]
+        });
+        if let Some(stats) = stats {
+            assert_eq!((stats.allocs, stats.bytes, stats.peak), (2, 160, 100));
+        }
+        let (inner, outer) = measure(|| {
+            drop(vec![0u8; 500]);
+            let (_, inner) = measure(|| vec![0u8; 10]);
+            inner
+        });
+        if let (Some(inner), Some(outer)) = (inner, outer) {
+            assert_eq!(inner.peak, 10);
+            assert_eq!(outer.peak, 500);
+        }
+        let (_, stats) = measure(|| {
+            let mut v = Vec::<u8>::with_capacity(100);
+            v.reserve_exact(300);
+            v.shrink_to(50);
+            v
+        });
+        if let Some(stats) = stats {
+            assert_eq!((stats.allocs, stats.bytes, stats.peak), (3, 300, 300));
+        }
+        assert_eq!(fmt_bytes(1536), "1.5KiB");
+    }
+}
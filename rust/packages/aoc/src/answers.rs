@@ -0,0 +1,144 @@
+use std::{collections::BTreeMap, fmt, path::PathBuf};
+
+use serde::Deserialize;
+
+use crate::{demo, runner, Answer, Input, Solution};
+
+/// Known-good answers keyed by day, read from `answers.toml`:
+///
+/// ```toml
+/// [day_05]
+/// part1 = "TBVFVDZPN"
+/// part2 = "VLCWHTDSZ"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<u8, [Option<Answer>; 2]>);
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, std::io::Error),
+    Invalid(PathBuf, String),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(p, e) => write!(f, "failed to read {}: {}", p.display(), e),
+            AnswersError::Invalid(p, reason) => write!(f, "{}: {}", p.display(), reason),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+impl Answers {
+    pub fn default_path() -> PathBuf {
+        crate::workspace_dir().join("answers.toml")
+    }
+
+    pub fn load() -> Result<Self, AnswersError> {
+        let path = Answers::default_path();
+        let text = std::fs::read_to_string(&path).map_err(|e| AnswersError::Io(path.clone(), e))?;
+        Answers::parse(&text).map_err(|reason| AnswersError::Invalid(path, reason))
+    }
+
+    /// Answers may be written as toml integers, strings, or arrays of rows
+    /// for multi-line answers.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table: toml::Table = text.parse().map_err(|e: toml::de::Error| e.to_string())?;
+        let mut answers = Answers::default();
+        for (key, parts) in table {
+            let day = key
+                .strip_prefix("day_")
+                .and_then(|d| d.parse::<u8>().ok())
+                .ok_or_else(|| format!("`{}` is not a `day_NN` table", key))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("`{}` is not a table", key))?;
+            let mut recorded = [None, None];
+            for (part, value) in parts {
+                let slot = match part.as_str() {
+                    "part1" => &mut recorded[0],
+                    "part2" => &mut recorded[1],
+                    _ => return Err(format!("unknown key `{}.{}`", key, part)),
+                };
+                let answer = Answer::deserialize(value.clone())
+                    .map_err(|e| format!("`{}.{}`: {}", key, part, e.message()))?;
+                *slot = Some(answer);
+            }
+            answers.0.insert(day, recorded);
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        let parts = self.0.get(&day)?;
+        parts.get(usize::from(part).checked_sub(1)?)?.as_ref()
+    }
+}
+
+/// Solves `S` on `input` and panics on any part that differs from its
+/// recorded answer. A day without recorded answers fails too, so a new day
+/// can't silently skip the check.
+pub fn assert_recorded<S: Solution>(input: Input) {
+    let answers = Answers::load().unwrap_or_else(|e| panic!("{}", e));
+    let lines = crate::lines(input.clone());
+    let res =
+        runner::run::<S>(&lines, &[1, 2]).unwrap_or_else(|e| panic!("{}", e.in_file(input.path())));
+    let mut checked = 0;
+    for part in res.parts {
+        if let Some(expected) = answers.get(S::DAY, part.part) {
+            let what = format!(
+                "day {} part {} (recorded in answers.toml)",
+                S::DAY,
+                part.part
+            );
+            demo::assert_answer(&input, &what, expected, &part.answer);
+            checked += 1;
+        }
+    }
+    assert!(
+        checked > 0,
+        "answers.toml has no answers for day {}",
+        S::DAY
+    );
+}
+
+/// Generates a test asserting a day's answers on its real input against
+/// `answers.toml`. Use it inside the day's test module.
+#[macro_export]
+macro_rules! answer_tests {
+    ($solution:ty) => {
+        #[test]
+        fn test_recorded_answers() {
+            $crate::answers::assert_recorded::<$solution>($crate::input!("input_p1.txt"));
+        }
+    };
+}
+
+#[cfg(test)]
+mod test_answers {
+    use crate::answers::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse(
+            "[day_05]\npart1 = \"CMZ\"\n\n[day_07]\npart1 = 95437\npart2 = 24933642\n\n[day_10]\npart2 = [\"#.\", \".#\"]\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(5, 1), Some(&"CMZ".into()));
+        assert_eq!(answers.get(5, 2), None);
+        assert_eq!(answers.get(7, 2), Some(&Answer::Int(24933642)));
+        assert_eq!(answers.get(7, 0), None);
+        assert_eq!(answers.get(10, 2), Some(&Answer::from("#.\n.#")));
+        assert!(Answers::parse("[day_1]\npart3 = 1\n").is_err());
+        assert!(Answers::parse("[seven]\npart1 = 1\n").is_err());
+        assert!(Answers::parse("[day_1]\npart1 = 1.5\n").is_err());
+    }
+
+    #[test]
+    fn test_workspace_answers_load() {
+        let answers = Answers::load().unwrap();
+        assert_eq!(answers.get(5, 2), Some(&"VLCWHTDSZ".into()));
+    }
+}
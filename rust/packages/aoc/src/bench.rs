@@ -0,0 +1,157 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::{Serialize, Serializer};
+
+use crate::{ParseError, Solution};
+
+/// How many untimed warmup rounds to run before taking `samples` timings.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Config {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            warmup: 3,
+            samples: 20,
+        }
+    }
+}
+
+pub(crate) fn nanos<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u128(d.as_nanos())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "p95_ns", serialize_with = "nanos")]
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarises at least one sample, using nearest-rank percentiles.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        samples.sort();
+        let rank = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        Stats {
+            min: samples[0],
+            median: rank(50),
+            p95: rank(95),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Bench {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+fn measure<T>(config: &Config, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+    let samples = (0..config.samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(samples)
+}
+
+/// Times parsing and each part separately. Parts are timed against a single
+/// parsed input, so they don't pay for parsing again.
+pub fn bench<S: Solution>(lines: &[String], config: &Config) -> Result<Bench, ParseError> {
+    let parsed = S::parse(lines)?;
+    let parse = measure(config, || S::parse(black_box(lines)));
+    Ok(Bench {
+        day: S::DAY,
+        parse,
+        part1: measure(config, || S::part1(black_box(&parsed))),
+        part2: measure(config, || S::part2(black_box(&parsed))),
+    })
+}
+
+/// A set of benches plus whatever identifies the run, e.g. a commit, so
+/// exported reports can be told apart.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub label: Option<String>,
+    pub config: Config,
+    pub benches: Vec<Bench>,
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("bench reports always serialize")
+    }
+}
+
+#[cfg(test)]
+mod test_bench {
+    use crate::bench::*;
+    use crate::Answer;
+
+    struct Count;
+
+    impl Solution for Count {
+        const DAY: u8 = 3;
+        type Parsed = usize;
+
+        fn parse(lines: &[String]) -> Result<usize, ParseError> {
+            Ok(lines.len())
+        }
+
+        fn part1(parsed: &usize) -> Answer {
+            (*parsed).into()
+        }
+
+        fn part2(parsed: &usize) -> Answer {
+            (parsed * 2).into()
+        }
+    }
+
+    #[test]
+    fn test_stats_percentiles() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(10));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+        let one = Stats::from_samples(vec![Duration::from_nanos(7)]);
+        assert_eq!((one.min, one.median, one.p95), (one.min, one.min, one.min));
+    }
+
+    #[test]
+    fn test_bench_report_json() {
+        let lines = vec!["a".to_string(), "b".to_string()];
+        let config = Config {
+            warmup: 1,
+            samples: 5,
+        };
+        let report = Report {
+            label: Some("abc123".to_string()),
+            config,
+            benches: vec![bench::<Count>(&lines, &config).unwrap()],
+        };
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["label"], "abc123");
+        assert_eq!(json["config"]["samples"], 5);
+        assert_eq!(json["benches"][0]["day"], 3);
+        assert!(json["benches"][0]["part2"]["p95_ns"].is_u64());
+    }
+}
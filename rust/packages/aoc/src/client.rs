@@ -0,0 +1,210 @@
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2022;
+pub const USER_AGENT: &str = concat!(
+    "github.com/cdaringe/aoc_2022 aoc/",
+    env!("CARGO_PKG_VERSION"),
+    " (ureq)"
+);
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Status {
+        method: &'static str,
+        url: String,
+        status: u16,
+        body: String,
+    },
+    Transport {
+        url: String,
+        reason: String,
+    },
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => {
+                write!(f, "no session cookie, set AOC_SESSION or pass --session")
+            }
+            ClientError::Status {
+                method,
+                url,
+                status,
+                body,
+            } => write!(f, "{} {} returned {}: {}", method, url, status, body.trim()),
+            ClientError::Transport { url, reason } => {
+                write!(f, "request to {} failed: {}", url, reason)
+            }
+            ClientError::Io(p, e) => write!(f, "failed to write {}: {}", p.display(), e),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// Talks to adventofcode.com (or a stand-in at `base_url`) as the owner of
+/// `session`.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    pub year: u16,
+}
+
+impl Client {
+    pub fn new(session: impl Into<String>) -> Result<Self, ClientError> {
+        let session = session.into();
+        if session.trim().is_empty() {
+            return Err(ClientError::MissingSession);
+        }
+        Ok(Client {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: session.trim().to_string(),
+            year: YEAR,
+        })
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day)
+    }
+
+    pub fn get(&self, url: &str) -> Result<String, ClientError> {
+        self.send("GET", url, self.request("GET", url).call())
+    }
+
+    pub fn post_form(&self, url: &str, fields: &[(&str, &str)]) -> Result<String, ClientError> {
+        self.send("POST", url, self.request("POST", url).send_form(fields))
+    }
+
+    pub fn fetch_input(&self, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    fn send(
+        &self,
+        method: &'static str,
+        url: &str,
+        res: Result<ureq::Response, ureq::Error>,
+    ) -> Result<String, ClientError> {
+        let transport = |reason: String| ClientError::Transport {
+            url: url.to_string(),
+            reason,
+        };
+        match res {
+            Ok(resp) => resp.into_string().map_err(|e| transport(e.to_string())),
+            Err(ureq::Error::Status(status, resp)) => Err(ClientError::Status {
+                method,
+                url: url.to_string(),
+                status,
+                body: resp.into_string().unwrap_or_default(),
+            }),
+            Err(ureq::Error::Transport(t)) => Err(transport(t.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+pub fn is_cached(dest: &Path) -> bool {
+    std::fs::metadata(dest)
+        .map(|m| m.len() > 0)
+        .unwrap_or(false)
+}
+
+/// Writes day `day`'s input to `dest`, unless a non-empty file is already
+/// there. Cached inputs are never refetched, and `client` is only called to
+/// build a client on a cache miss, so no session is needed otherwise.
+pub fn cached_input(
+    client: impl FnOnce() -> Result<Client, ClientError>,
+    day: u8,
+    dest: &Path,
+) -> Result<Fetched, ClientError> {
+    if is_cached(dest) {
+        return Ok(Fetched::Cached(dest.to_path_buf()));
+    }
+    download_input(&client()?, day, dest).map(Fetched::Downloaded)
+}
+
+fn download_input(client: &Client, day: u8, dest: &Path) -> Result<PathBuf, ClientError> {
+    let body = client.fetch_input(day)?;
+    let io_err = |e| ClientError::Io(dest.to_path_buf(), e);
+    if let Some(dir) = dest.parent() {
+        std::fs::create_dir_all(dir).map_err(io_err)?;
+    }
+    let tmp = dest.with_extension("txt.partial");
+    std::fs::write(&tmp, body).map_err(io_err)?;
+    std::fs::rename(&tmp, dest).map_err(io_err)?;
+    Ok(dest.to_path_buf())
+}
+
+#[cfg(test)]
+mod test_client {
+    use crate::client::*;
+    use crate::mock_server::MockServer;
+
+    #[test]
+    fn test_cached_input_fetches_once() {
+        let server = MockServer::start(vec![(200, "1000\n2000\n")]);
+        let client = || Ok(Client::new("abc123")?.with_base_url(&server.url));
+        let dest = std::env::temp_dir()
+            .join(format!("aoc_fetch_{}", std::process::id()))
+            .join("input_p1.txt");
+        let _ = std::fs::remove_file(&dest);
+
+        assert_eq!(
+            cached_input(client, 1, &dest).unwrap(),
+            Fetched::Downloaded(dest.clone())
+        );
+        assert_eq!(
+            cached_input(|| Err(ClientError::MissingSession), 1, &dest).unwrap(),
+            Fetched::Cached(dest.clone())
+        );
+        assert_eq!(std::fs::read_to_string(&dest).unwrap(), "1000\n2000\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2022/day/1/input "));
+        assert!(requests[0].contains("cookie: session=abc123"));
+        assert!(requests[0].contains(&format!("user-agent: {}", USER_AGENT)));
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        assert!(matches!(Client::new(" "), Err(ClientError::MissingSession)));
+        let server = MockServer::start(vec![(400, "Please log in")]);
+        let client = Client::new("bad").unwrap().with_base_url(&server.url);
+        match client.fetch_input(3) {
+            Err(e @ ClientError::Status { .. }) => assert_eq!(
+                e.to_string(),
+                format!(
+                    "GET {}/2022/day/3/input returned 400: Please log in",
+                    server.url
+                )
+            ),
+            other => panic!("expected status error, got {:?}", other),
+        }
+    }
+}
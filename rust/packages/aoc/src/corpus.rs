@@ -0,0 +1,105 @@
+use std::path::{Path, PathBuf};
+
+use crate::{Answer, AocError};
+
+/// One input in a directory of inputs, e.g. `inputs/day_07/alice.txt`, with
+/// the answers from its sibling `alice.answer` if there is one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case {
+    pub input: PathBuf,
+    pub expected: [Option<Answer>; 2],
+}
+
+impl Case {
+    pub fn name(&self) -> String {
+        self.input
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn expected(&self, part: u8) -> Option<&Answer> {
+        self.expected
+            .get(usize::from(part).checked_sub(1)?)?
+            .as_ref()
+    }
+}
+
+/// Parses an `.answer` file: part 1's answer on the first line and part 2's
+/// on the second. A blank or missing line means the answer is unknown.
+pub fn parse_answers(text: &str) -> [Option<Answer>; 2] {
+    let mut lines = text.lines().map(str::trim);
+    let mut next = || {
+        lines
+            .next()
+            .filter(|l| !l.is_empty())
+            .map(|l| l.parse().unwrap_or_else(|e| match e {}))
+    };
+    [next(), next()]
+}
+
+/// Every `*.txt` in `dir`, sorted by name.
+pub fn cases(dir: &Path) -> Result<Vec<Case>, AocError> {
+    let entries = std::fs::read_dir(dir).map_err(|e| AocError::from_io(dir, e))?;
+    let mut inputs = vec![];
+    for entry in entries {
+        let path = entry.map_err(|e| AocError::from_io(dir, e))?.path();
+        if path.is_file() && path.extension().is_some_and(|e| e == "txt") {
+            inputs.push(path);
+        }
+    }
+    inputs.sort();
+    inputs
+        .into_iter()
+        .map(|input| {
+            let answer = input.with_extension("answer");
+            let expected = match std::fs::read_to_string(&answer) {
+                Ok(text) => parse_answers(&text),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => [None, None],
+                Err(e) => return Err(AocError::from_io(&answer, e)),
+            };
+            Ok(Case { input, expected })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_corpus {
+    use crate::corpus::*;
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("95437\n24933642\n"),
+            [Some(Answer::Int(95437)), Some(Answer::Int(24933642))]
+        );
+        assert_eq!(parse_answers("CMZ\n"), [Some("CMZ".into()), None]);
+        assert_eq!(parse_answers("\n 7 \n"), [None, Some(Answer::Int(7))]);
+    }
+
+    #[test]
+    fn test_cases_pair_inputs_with_answers() {
+        let dir = std::env::temp_dir().join(format!("aoc_corpus_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        for (name, text) in [
+            ("bob.txt", "2"),
+            ("alice.txt", "1"),
+            ("alice.answer", "3\n4\n"),
+            ("notes.md", "not an input"),
+        ] {
+            std::fs::write(dir.join(name), text).unwrap();
+        }
+        let cases = cases(&dir).unwrap();
+        assert_eq!(cases.len(), 2);
+        assert_eq!(cases[0].name(), "alice.txt");
+        assert_eq!(cases[0].expected(1), Some(&Answer::Int(3)));
+        assert_eq!(cases[0].expected(2), Some(&Answer::Int(4)));
+        assert_eq!(cases[1].name(), "bob.txt");
+        assert_eq!(cases[1].expected(1), None);
+        assert!(matches!(
+            super::cases(&dir.join("missing")),
+            Err(AocError::NotFound(_))
+        ));
+    }
+}
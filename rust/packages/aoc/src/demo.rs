@@ -0,0 +1,127 @@
+use crate::{Answer, Input, Solution};
+
+/// A line diff of `expected` against `actual`, prefixing lines only in the
+/// former with `-` and lines only in the latter with `+`.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut out = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        let (e, a) = (expected.get(i), actual.get(i));
+        if e == a {
+            out += &format!("  {}\n", e.unwrap());
+            continue;
+        }
+        if let Some(e) = e {
+            out += &format!("- {}\n", e);
+        }
+        if let Some(a) = a {
+            out += &format!("+ {}\n", a);
+        }
+    }
+    out
+}
+
+/// Panics with the input's path and a diff when `actual` isn't `expected`.
+#[track_caller]
+pub fn assert_answer(input: &Input, what: &str, expected: &Answer, actual: &Answer) {
+    if !actual.matches(expected) {
+        panic!(
+            "{} on {}\n--- expected\n+++ actual\n{}",
+            what,
+            input.path().display(),
+            diff(&expected.to_string(), &actual.to_string())
+        );
+    }
+}
+
+#[track_caller]
+pub fn check<S: Solution>(
+    input: Input,
+    part: u8,
+    solve: fn(&S::Parsed) -> Answer,
+    expected: Answer,
+) {
+    let lines = crate::lines(input.clone());
+    let parsed = S::parse(&lines).unwrap_or_else(|e| panic!("{}", e.in_file(input.path())));
+    let actual = solve(&parsed);
+    let what = format!("day {} part {}", S::DAY, part);
+    assert_answer(&input, &what, &expected, &actual);
+}
+
+/// Generates a test that solves one part of a day on an input file in the
+/// day's package and compares it with the expected answer:
+///
+/// ```ignore
+/// aoc::demo_test!(test_demo_input_p1, Day05, part1, "input_p1_demo.txt", "CMZ");
+/// ```
+#[macro_export]
+macro_rules! demo_test {
+    ($name:ident, $solution:ty, part1, $file:expr, $expected:expr) => {
+        $crate::demo_test!(@test $name, $solution, part1, 1, $file, $expected);
+    };
+    ($name:ident, $solution:ty, part2, $file:expr, $expected:expr) => {
+        $crate::demo_test!(@test $name, $solution, part2, 2, $file, $expected);
+    };
+    (@test $name:ident, $solution:ty, $part:ident, $n:literal, $file:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            $crate::demo::check::<$solution>(
+                $crate::input!($file),
+                $n,
+                <$solution as $crate::Solution>::$part,
+                $crate::Answer::from($expected),
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod test_demo {
+    use crate::demo::*;
+    use crate::ParseError;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        const DAY: u8 = 9;
+        type Parsed = String;
+
+        fn parse(lines: &[String]) -> Result<String, ParseError> {
+            Ok(lines.join("\n"))
+        }
+
+        fn part1(parsed: &String) -> Answer {
+            parsed.as_str().into()
+        }
+
+        fn part2(parsed: &String) -> Answer {
+            parsed.lines().count().into()
+        }
+    }
+
+    fn message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+        let err = std::panic::catch_unwind(f).unwrap_err();
+        err.downcast_ref::<String>().cloned().unwrap_or_default()
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff("a\nb\nc", "a\nx\nc\nd"), "  a\n- b\n+ x\n  c\n+ d\n");
+        assert_eq!(diff("7", "7"), "  7\n");
+    }
+
+    #[test]
+    fn test_check_reports_path_and_diff() {
+        let input = || Input::Embedded {
+            path: "demo.txt".into(),
+            text: "one\ntwo\n",
+        };
+        check::<Echo>(input(), 2, Echo::part2, 2.into());
+        let msg = message(|| check::<Echo>(input(), 1, Echo::part1, "one\nthree".into()));
+        assert_eq!(
+            msg,
+            "day 9 part 1 on demo.txt\n--- expected\n+++ actual\n  one\n- three\n+ two\n"
+        );
+    }
+}
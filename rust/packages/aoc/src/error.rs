@@ -0,0 +1,53 @@
+use std::{fmt, io, path::Path, path::PathBuf};
+
+#[derive(Debug)]
+pub enum AocError {
+    NotFound(PathBuf),
+    PermissionDenied(PathBuf),
+    InvalidUtf8(PathBuf),
+    Empty(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl AocError {
+    pub fn from_io(path: &Path, err: io::Error) -> Self {
+        let path = path.to_path_buf();
+        match err.kind() {
+            io::ErrorKind::NotFound => AocError::NotFound(path),
+            io::ErrorKind::PermissionDenied => AocError::PermissionDenied(path),
+            io::ErrorKind::InvalidData => AocError::InvalidUtf8(path),
+            _ => AocError::Io(path, err),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        match self {
+            AocError::NotFound(p)
+            | AocError::PermissionDenied(p)
+            | AocError::InvalidUtf8(p)
+            | AocError::Empty(p)
+            | AocError::Io(p, _) => p,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::NotFound(p) => write!(f, "input not found: {}", p.display()),
+            AocError::PermissionDenied(p) => write!(f, "permission denied: {}", p.display()),
+            AocError::InvalidUtf8(p) => write!(f, "input is not valid utf-8: {}", p.display()),
+            AocError::Empty(p) => write!(f, "input is empty: {}", p.display()),
+            AocError::Io(p, e) => write!(f, "failed to read {}: {}", p.display(), e),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
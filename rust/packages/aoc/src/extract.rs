@@ -0,0 +1,270 @@
+use std::{fmt, path::Path};
+
+/// The example input and expected example answers pulled from a puzzle page.
+/// `answers[0]` is part 1's, `answers[1]` part 2's once it is unlocked.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    pub example: String,
+    pub answers: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ExtractError {
+    NoArticle,
+    NoExample,
+    NoTestModule(String),
+}
+
+impl fmt::Display for ExtractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtractError::NoArticle => write!(f, "no <article class=\"day-desc\"> in page"),
+            ExtractError::NoExample => write!(f, "no <pre><code> example in part 1"),
+            ExtractError::NoTestModule(module) => write!(
+                f,
+                "`mod {}` isn't a top-level `mod {} {{ .. }}` block closed by a `}}` line",
+                module, module
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ExtractError {}
+
+pub fn extract(html: &str) -> Result<Puzzle, ExtractError> {
+    let articles: Vec<&str> = html
+        .split("<article")
+        .skip(1)
+        .filter_map(|a| a.split("</article>").next())
+        .collect();
+    let first = articles.first().ok_or(ExtractError::NoArticle)?;
+    let example = between(first, "<pre><code>", "</code></pre>")
+        .map(|block| unescape(&strip_tags(block)))
+        .ok_or(ExtractError::NoExample)?;
+    let answers = articles.iter().filter_map(|a| expected_answer(a)).collect();
+    Ok(Puzzle { example, answers })
+}
+
+// The example's answer is the last emphasised code span, e.g.
+// `<code><em>95437</em></code>`, falling back to the last single-word `<em>`.
+fn expected_answer(article: &str) -> Option<String> {
+    let code_em = article
+        .rsplit("<code><em>")
+        .next()
+        .filter(|_| article.contains("<code><em>"))
+        .and_then(|tail| tail.split("</em></code>").next());
+    let bare_em = || {
+        article
+            .split("<em>")
+            .skip(1)
+            .filter_map(|tail| tail.split("</em>").next())
+            .filter(|e| !e.is_empty() && !e.contains(char::is_whitespace) && !e.contains('<'))
+            .last()
+    };
+    code_em.or_else(bare_em).map(|a| unescape(&strip_tags(a)))
+}
+
+fn between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = s.find(start)? + start.len();
+    let to = s[from..].find(end)?;
+    Some(&s[from..from + to])
+}
+
+fn strip_tags(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut in_tag = false;
+    for c in s.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+/// An `aoc::demo_test!` asserting day `day`'s `part` answer on the demo
+/// input.
+pub fn test_stub(day: u8, part: u8, answer: &str) -> String {
+    format!(
+        r#"
+    aoc::demo_test!(
+        test_extracted_demo_p{part},
+        Day{day:02},
+        part{part},
+        "input_p1_demo.txt",
+        {answer:?}
+    );
+"#
+    )
+}
+
+/// Adds `stub` for `part` to the top-level test module `module`, creating it
+/// at the end of `source` if there is none. Returns `Ok(None)` when that
+/// part's stub is already there.
+pub fn insert_stub(
+    source: &str,
+    module: &str,
+    part: u8,
+    stub: &str,
+) -> Result<Option<String>, ExtractError> {
+    if source.contains(&format!("test_extracted_demo_p{},", part)) {
+        return Ok(None);
+    }
+    let empty_module = format!("mod {} {{}}", module);
+    let open_module = format!("mod {} {{", module);
+    let trimmed = source.trim_end();
+    let mut offset = 0;
+    let mut open_at = None;
+    for line in trimmed.split_inclusive('\n') {
+        let end = offset + line.len();
+        let text = line.trim_end();
+        match open_at {
+            None if text == empty_module => {
+                let updated = format!(
+                    "{}mod {} {{\n    use crate::*;\n{}}}{}",
+                    &trimmed[..offset],
+                    module,
+                    stub,
+                    &trimmed[offset + empty_module.len()..]
+                );
+                return Ok(Some(updated + "\n"));
+            }
+            None if text == open_module => open_at = Some(offset),
+            Some(_) if text == "}" => {
+                let updated = format!("{}{}{}", &trimmed[..offset], stub, &trimmed[offset..]);
+                return Ok(Some(updated + "\n"));
+            }
+            _ => {}
+        }
+        offset = end;
+    }
+    if open_at.is_some() || trimmed.contains(&format!("mod {}", module)) {
+        return Err(ExtractError::NoTestModule(module.to_string()));
+    }
+    Ok(Some(format!(
+        "{}\n\n#[cfg(test)]\nmod {} {{\n    use crate::*;\n{}}}\n",
+        trimmed, module, stub
+    )))
+}
+
+/// What `write_day` did with a day package.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Written {
+    pub demo: bool,
+    pub stubs: Vec<u8>,
+}
+
+/// Writes the example to `input_p1_demo.txt` (leaving a differing, non-empty
+/// file alone) and a test per known answer into the day's `src/lib.rs`.
+pub fn write_day(day_dir: &Path, day: u8, puzzle: &Puzzle) -> std::io::Result<Written> {
+    let module = format!("test_day_{:02}", day);
+    let demo_path = day_dir.join("input_p1_demo.txt");
+    let existing = std::fs::read_to_string(&demo_path).unwrap_or_default();
+    let demo = existing.trim().is_empty();
+    if demo {
+        std::fs::write(&demo_path, &puzzle.example)?;
+    }
+    let src_path = day_dir.join("src/lib.rs");
+    let mut source = std::fs::read_to_string(&src_path)?;
+    let mut stubs = vec![];
+    for (i, answer) in puzzle.answers.iter().enumerate() {
+        let part = i as u8 + 1;
+        let stub = test_stub(day, part, answer);
+        let inserted = insert_stub(&source, &module, part, &stub)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        if let Some(updated) = inserted {
+            source = updated;
+            stubs.push(part);
+        }
+    }
+    if !stubs.is_empty() {
+        std::fs::write(&src_path, source)?;
+    }
+    Ok(Written { demo, stubs })
+}
+
+#[cfg(test)]
+mod test_extract {
+    use crate::extract::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 4: Camp Cleanup ---</h2>
+<p>For example, consider the following list of section assignment pairs:</p>
+<pre><code>2-4,6-8
+2-3,4-5
+</code></pre>
+<p>In how many assignment pairs does one range <em>fully contain</em> the other?</p>
+<p>In this example, there are <code><em>2</em></code> such pairs.</p>
+</article>
+<p>Your puzzle answer was <code>500</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>So, in this example, the number of overlapping assignment pairs is <code><em>4</em></code>.</p>
+<p><em>In how many assignment pairs do the ranges overlap?</em></p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract_example_and_answers() {
+        let puzzle = extract(PAGE).unwrap();
+        assert_eq!(puzzle.example, "2-4,6-8\n2-3,4-5\n");
+        assert_eq!(puzzle.answers, vec!["2", "4"]);
+        let bare = r#"<article><pre><code>a &lt;b&gt;
+<em>c</em></code></pre><p>this is <em>24000</em>. <em>How many?</em></p></article>"#;
+        assert_eq!(
+            extract(bare).unwrap(),
+            Puzzle {
+                example: "a <b>\nc".to_string(),
+                answers: vec!["24000".to_string()]
+            }
+        );
+        assert_eq!(extract("<p>nope</p>"), Err(ExtractError::NoArticle));
+    }
+
+    #[test]
+    fn test_insert_stub() {
+        let src = "fn main() {}\n\n#[cfg(test)]\nmod test_day_04 {}\n";
+        let stub = test_stub(4, 1, "2");
+        let once = insert_stub(src, "test_day_04", 1, &stub).unwrap().unwrap();
+        assert!(once.contains("mod test_day_04 {\n    use crate::*;\n\n    aoc::demo_test!("));
+        assert!(once
+            .contains("Day04,\n        part1,\n        \"input_p1_demo.txt\",\n        \"2\"\n"));
+        assert!(once.ends_with("    );\n}\n"));
+        assert_eq!(insert_stub(&once, "test_day_04", 1, &stub), Ok(None));
+        let twice = insert_stub(&once, "test_day_04", 2, &test_stub(4, 2, "4"))
+            .unwrap()
+            .unwrap();
+        assert!(twice.contains("test_extracted_demo_p2,"));
+        assert!(twice.ends_with("    );\n}\n"));
+    }
+
+    #[test]
+    fn test_insert_stub_with_items_after_module() {
+        let src = "#[cfg(test)]\nmod test_day_04 {\n    fn f() {\n    }\n}\n\nfn main() {}\n";
+        let updated = insert_stub(src, "test_day_04", 1, &test_stub(4, 1, "2"))
+            .unwrap()
+            .unwrap();
+        let (module, rest) = updated.split_once("\n}\n").unwrap();
+        assert!(module.contains("    fn f() {\n    }\n\n    aoc::demo_test!("));
+        assert_eq!(rest, "\nfn main() {}\n");
+        let missing = insert_stub("fn main() {}\n", "test_day_04", 1, "")
+            .unwrap()
+            .unwrap();
+        assert!(missing.ends_with("#[cfg(test)]\nmod test_day_04 {\n    use crate::*;\n}\n"));
+        let nested = "mod outer {\n    mod test_day_04 {\n    }\n}\n";
+        assert_eq!(
+            insert_stub(nested, "test_day_04", 1, ""),
+            Err(ExtractError::NoTestModule("test_day_04".to_string()))
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use std::{
+    borrow::Cow,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use crate::AocError;
+
+/// Where a day's input comes from. `Embedded` inputs are baked in at compile
+/// time when the `embed` feature is on, so a binary runs without the checkout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Path(PathBuf),
+    Embedded { path: PathBuf, text: &'static str },
+}
+
+/// Resolves an input file relative to the calling crate's manifest directory,
+/// so lookups don't depend on where `cargo` was invoked from. With the `embed`
+/// feature the file contents are compiled into the binary instead.
+///
+/// ```no_run
+/// let lines = aoc::lines(aoc::input!("input_p1.txt"));
+/// ```
+#[cfg(not(feature = "embed"))]
+#[macro_export]
+macro_rules! input {
+    ($name:expr) => {
+        $crate::Input::Path(::std::path::PathBuf::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/",
+            $name
+        )))
+    };
+}
+
+#[cfg(feature = "embed")]
+#[macro_export]
+macro_rules! input {
+    ($name:expr) => {
+        $crate::Input::Embedded {
+            path: ::std::path::PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/", $name)),
+            text: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $name)),
+        }
+    };
+}
+
+/// The input a day binary should run on: `input_p1.txt`, or
+/// `input_p1_demo.txt` when passed `--demo`, or any file given by
+/// `--input <path>` (`-` for stdin).
+#[macro_export]
+macro_rules! day_input {
+    () => {
+        $crate::Input::select(
+            $crate::input!("input_p1.txt"),
+            $crate::input!("input_p1_demo.txt"),
+        )
+    };
+}
+
+impl Input {
+    pub fn path(&self) -> &Path {
+        match self {
+            Input::Path(p) | Input::Embedded { path: p, .. } => p,
+        }
+    }
+
+    pub fn select(real: Input, demo: Input) -> Input {
+        Input::select_from(std::env::args().skip(1), real, demo)
+    }
+
+    pub fn select_from(args: impl IntoIterator<Item = String>, real: Input, demo: Input) -> Input {
+        let mut selected = real;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--demo" {
+                selected = demo.clone();
+            } else if arg == "--input" {
+                if let Some(path) = args.next() {
+                    return Input::Path(path.into());
+                }
+            } else if let Some(path) = arg.strip_prefix("--input=") {
+                return Input::Path(path.into());
+            }
+        }
+        selected
+    }
+
+    /// Reads the whole input. Relative paths resolve against the current
+    /// directory and `-` reads stdin.
+    pub(crate) fn read(&self) -> Result<(PathBuf, Cow<'static, str>), AocError> {
+        match self {
+            Input::Embedded { path, text } => Ok((path.clone(), Cow::Borrowed(text))),
+            Input::Path(p) if p == Path::new("-") => {
+                let mut bytes = vec![];
+                std::io::stdin()
+                    .read_to_end(&mut bytes)
+                    .map_err(|e| AocError::from_io(p, e))?;
+                decode(p.clone(), bytes)
+            }
+            Input::Path(p) => {
+                let path = crate::resolve(p);
+                let bytes = std::fs::read(&path).map_err(|e| AocError::from_io(&path, e))?;
+                decode(path, bytes)
+            }
+        }
+    }
+}
+
+fn decode(path: PathBuf, bytes: Vec<u8>) -> Result<(PathBuf, Cow<'static, str>), AocError> {
+    match String::from_utf8(bytes) {
+        Ok(text) => Ok((path, Cow::Owned(text))),
+        Err(_) => Err(AocError::InvalidUtf8(path)),
+    }
+}
+
+impl<P: AsRef<Path>> From<P> for Input {
+    fn from(p: P) -> Self {
+        Input::Path(p.as_ref().to_path_buf())
+    }
+}
+
+#[cfg(test)]
+mod test_input {
+    use crate::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_select_from_args() {
+        let real = Input::from("real.txt");
+        let demo = Input::Embedded {
+            path: "demo.txt".into(),
+            text: "1\n2\n",
+        };
+        let pick = |a: &str| Input::select_from(args(a), real.clone(), demo.clone());
+        assert_eq!(pick(""), real);
+        assert_eq!(pick("--demo"), demo);
+        assert_eq!(pick("--demo --input x.txt"), Input::from("x.txt"));
+        assert_eq!(pick("--input=y.txt --demo"), Input::from("y.txt"));
+        assert_eq!(try_lines(demo).unwrap(), vec!["1", "2"]);
+    }
+
+    #[cfg(feature = "embed")]
+    #[test]
+    fn test_input_macro_embeds() {
+        let input = input!("Cargo.toml");
+        assert!(matches!(input, Input::Embedded { text, .. } if text.contains("[package]")));
+    }
+}
@@ -1,38 +1,176 @@
-use std::{self, path::PathBuf};
+use std::{
+    self,
+    path::{Path, PathBuf},
+};
 
-pub fn lines(name: &str) -> Vec<String> {
-    let pathbuf: PathBuf = [
-        &std::env::current_dir()
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod corpus;
+pub mod demo;
+mod error;
+pub mod extract;
+mod input;
+#[cfg(all(test, feature = "client"))]
+mod mock_server;
+pub mod parse;
+mod parse_error;
+pub mod runner;
+pub mod scaffold;
+mod sections;
+mod solution;
+mod stream;
+#[cfg(feature = "client")]
+pub mod submit;
+
+pub use error::AocError;
+pub use input::Input;
+pub use parse_error::{ParseError, Span};
+pub use sections::{exact_sections, sections, Section, SectionError};
+pub use solution::{Answer, Solution};
+pub use stream::{lines_iter, LinesIter};
+
+/// The cargo workspace root, i.e. the directory holding `packages/`.
+pub fn workspace_dir() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir
+        .ancestors()
+        .nth(2)
+        .unwrap_or(manifest_dir)
+        .to_path_buf()
+}
+
+/// The package directory for a given day, e.g. `packages/day_07`.
+pub fn day_dir(day: u8) -> PathBuf {
+    workspace_dir()
+        .join("packages")
+        .join(format!("day_{:02}", day))
+}
+
+fn resolve(name: &Path) -> PathBuf {
+    match std::env::current_dir() {
+        Ok(cwd) => cwd.join(name),
+        Err(_) => name.to_path_buf(),
+    }
+}
+
+pub fn try_lines(input: impl Into<Input>) -> Result<Vec<String>, AocError> {
+    let (pathbuf, file_str) = input.into().read()?;
+    split_lines(pathbuf, &file_str)
+}
+
+/// Splits on `\n` or `\r\n`, drops a leading UTF-8 BOM and trailing blank
+/// lines, like [`LinesIter`]. Whitespace at the end of a line is kept.
+fn split_lines(path: PathBuf, text: &str) -> Result<Vec<String>, AocError> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut lines: Vec<String> = text
+        .split('\n')
+        .map(|l| l.strip_suffix('\r').unwrap_or(l).to_string())
+        .collect();
+    while lines.last().is_some_and(String::is_empty) {
+        lines.pop();
+    }
+    match lines.is_empty() {
+        true => Err(AocError::Empty(path)),
+        false => Ok(lines),
+    }
+}
+
+pub fn lines(input: impl Into<Input>) -> Vec<String> {
+    match try_lines(input) {
+        Ok(lines) => lines,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Prints tracing events to stderr: warnings by default, debug events with
+/// `verbosity` 1 (`-v`) and everything with 2 or more (`-vv`).
+#[cfg(feature = "logging")]
+pub fn init_logging(verbosity: u8) {
+    let level = match verbosity {
+        0 => tracing::Level::WARN,
+        1 => tracing::Level::DEBUG,
+        _ => tracing::Level::TRACE,
+    };
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::IsTerminal::is_terminal(&std::io::stderr()))
+        .init();
+}
+
+/// Unwraps a loader result, or prints the error and exits non-zero. Meant for
+/// day binaries, where an unwinding panic is noise.
+pub fn or_exit<T, E: std::fmt::Display>(res: Result<T, E>) -> T {
+    match res {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_aoc {
+    use crate::*;
+
+    fn tmp_input(name: &str, contents: &[u8]) -> String {
+        let path = std::env::temp_dir().join(format!("aoc_{}_{}", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn test_try_lines_ok() {
+        let path = tmp_input("ok.txt", b"a\nb\n\nc\n\n");
+        assert_eq!(try_lines(&path).unwrap(), vec!["a", "b", "", "c"]);
+    }
+
+    #[test]
+    fn test_try_lines_crlf_and_bom() {
+        let crlf = tmp_input("crlf.txt", b"2-4,6-8\r\n\r\n2-3,4-5\r\n");
+        assert_eq!(try_lines(&crlf).unwrap(), vec!["2-4,6-8", "", "2-3,4-5"]);
+        let bom = tmp_input("bom.txt", "\u{feff}1000\n2000\n".as_bytes());
+        assert_eq!(try_lines(&bom).unwrap(), vec!["1000", "2000"]);
+        let empty = tmp_input("bom_empty.txt", "\u{feff}\r\n".as_bytes());
+        assert!(matches!(try_lines(&empty), Err(AocError::Empty(_))));
+    }
+
+    #[test]
+    fn test_try_lines_keeps_trailing_spaces_like_lines_iter() {
+        let padded = tmp_input("padded.txt", b"[A]    \n 1   2 \r\n\n\n");
+        let streamed: Vec<String> = lines_iter(&padded)
             .unwrap()
-            .to_string_lossy()
-            .to_owned(),
-        name,
-    ]
-    .iter()
-    .collect();
-    let str = pathbuf.to_string_lossy().to_string();
-    if let Ok(file_str) = std::fs::read_to_string(pathbuf) {
-        file_str
-            .trim_end()
-            .split('\n')
-            .map(|x| {
-                let y = String::from(x);
-                y
-            })
-            .collect()
-    } else {
-        panic!("input not found: {}", &str)
-    }
-}
-
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-//     use crate::*;
-
-//     #[test]
-//     fn it_works() {
-//         let result = add(2, 2);
-//         assert_eq!(result, 4);
-//     }
-// }
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(try_lines(&padded).unwrap(), vec!["[A]    ", " 1   2 "]);
+        assert_eq!(try_lines(&padded).unwrap(), streamed);
+        let blank = tmp_input("blank.txt", b"\n \n");
+        assert_eq!(try_lines(&blank).unwrap(), vec!["", " "]);
+    }
+
+    #[test]
+    fn test_try_lines_errors() {
+        match try_lines("./definitely_missing.txt") {
+            Err(AocError::NotFound(p)) => assert!(p.ends_with("definitely_missing.txt")),
+            other => panic!("expected NotFound, got {:?}", other),
+        }
+        let empty = tmp_input("empty.txt", b"\n\r\n\n");
+        assert!(matches!(try_lines(&empty), Err(AocError::Empty(_))));
+        let binary = tmp_input("binary.txt", &[0xff, 0xfe, 0x00]);
+        let err = try_lines(&binary).unwrap_err();
+        assert!(matches!(err, AocError::InvalidUtf8(_)));
+        assert_eq!(err.path().to_string_lossy(), binary);
+    }
+
+    #[test]
+    fn test_input_macro_is_manifest_relative() {
+        let input = input!("src/lib.rs");
+        assert!(input.path().is_absolute());
+        assert!(input.path().ends_with("packages/aoc/src/lib.rs"));
+        assert!(!try_lines(input).unwrap().is_empty());
+    }
+}
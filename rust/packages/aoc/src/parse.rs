@@ -0,0 +1,481 @@
+//! Pulling integers out of input lines without allocating. Every helper
+//! takes the line's 0-based index in the input so its [`ParseError`]s point
+//! at the right line.
+
+use std::{any::type_name, marker::PhantomData, ops::Range, str::FromStr};
+
+use crate::ParseError;
+
+/// An integer type the helpers can extract. Signed types also pick up a `-`
+/// directly in front of the digits.
+pub trait Int: FromStr + Copy {
+    const SIGNED: bool;
+}
+
+macro_rules! int {
+    ($signed:literal: $($t:ty),*) => {
+        $(impl Int for $t {
+            const SIGNED: bool = $signed;
+        })*
+    };
+}
+
+int!(false: u8, u16, u32, u64, u128, usize);
+int!(true: i8, i16, i32, i64, i128, isize);
+
+fn parse_at<T: Int>(index: usize, line: &str, span: Range<usize>) -> Result<T, ParseError> {
+    let text = &line[span.clone()];
+    text.parse().map_err(|_| {
+        let digits = text.trim_start_matches(['-', '+']);
+        let expected = match !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+            true => format!("a number that fits in {}", type_name::<T>()),
+            false => format!("a number ({})", type_name::<T>()),
+        };
+        ParseError::at(index, line, span, expected)
+    })
+}
+
+/// Every integer in a line, ignoring whatever is between them. See [`ints`].
+pub struct Ints<'a, T> {
+    index: usize,
+    line: &'a str,
+    at: usize,
+    int: PhantomData<T>,
+}
+
+impl<T: Int> Ints<'_, T> {
+    fn next_span(&mut self) -> Option<Range<usize>> {
+        let bytes = self.line.as_bytes();
+        let digit = self.at + bytes[self.at..].iter().position(u8::is_ascii_digit)?;
+        let end = digit
+            + bytes[digit..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+        let start = match T::SIGNED && digit > self.at && bytes[digit - 1] == b'-' {
+            true => digit - 1,
+            false => digit,
+        };
+        self.at = end;
+        Some(start..end)
+    }
+}
+
+impl<T: Int> Iterator for Ints<'_, T> {
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let span = self.next_span()?;
+        Some(parse_at(self.index, self.line, span))
+    }
+}
+
+/// Every integer in `line`, e.g. `1, 2, 3` from `move 1 from 2 to 3`. With a
+/// signed `T`, `2-4` is `2` and `-4`; use [`fields`] for ranges like that.
+///
+/// ```
+/// let nums: Vec<i32> = aoc::parse::ints(0, "x=-3, y=14").collect::<Result<_, _>>().unwrap();
+/// assert_eq!(nums, vec![-3, 14]);
+/// ```
+pub fn ints<T: Int>(index: usize, line: &str) -> Ints<'_, T> {
+    Ints {
+        index,
+        line,
+        at: 0,
+        int: PhantomData,
+    }
+}
+
+/// Like [`ints`], but requires exactly `N` integers.
+///
+/// ```
+/// let [n, from, to] = aoc::parse::exact_ints::<usize, 3>(0, "move 1 from 2 to 3").unwrap();
+/// assert_eq!((n, from, to), (1, 2, 3));
+/// ```
+pub fn exact_ints<T: Int, const N: usize>(index: usize, line: &str) -> Result<[T; N], ParseError> {
+    let mut it = ints::<T>(index, line);
+    let mut out = [None; N];
+    for (found, slot) in out.iter_mut().enumerate() {
+        match it.next() {
+            Some(n) => *slot = Some(n?),
+            None => {
+                let end = line.len()..line.len();
+                return Err(ParseError::at(index, line, end, count(N)).found(found));
+            }
+        }
+    }
+    if let Some(extra) = it.next_span() {
+        return Err(ParseError::at(
+            index,
+            line,
+            extra,
+            format!("only {}", count(N)),
+        ));
+    }
+    Ok(out.map(|n| n.expect("every slot is filled")))
+}
+
+fn count(n: usize) -> String {
+    match n {
+        1 => "1 number".to_string(),
+        n => format!("{} numbers", n),
+    }
+}
+
+/// The integers in a line made only of integers and separators. See
+/// [`fields`].
+pub struct Fields<'a, 's, T> {
+    index: usize,
+    line: &'a str,
+    separators: &'s [char],
+    at: Option<usize>,
+    int: PhantomData<T>,
+}
+
+impl<T: Int> Fields<'_, '_, T> {
+    fn next_span(&mut self) -> Option<Range<usize>> {
+        let start = self.at?;
+        let rest = &self.line[start..];
+        let (end, next) = match rest.find(self.separators) {
+            Some(i) => {
+                let sep = rest[i..].chars().next().map_or(1, char::len_utf8);
+                (start + i, Some(start + i + sep))
+            }
+            None => (self.line.len(), None),
+        };
+        self.at = next;
+        Some(start..end)
+    }
+}
+
+impl<T: Int> Iterator for Fields<'_, '_, T> {
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let span = self.next_span()?;
+        Some(parse_at(self.index, self.line, span))
+    }
+}
+
+/// Splits `line` on any of `separators` and parses every field, so unlike
+/// [`ints`] anything that isn't an integer is an error. Use [`pattern`]
+/// when the separators come in a fixed order.
+///
+/// ```
+/// let nums: Vec<u8> = aoc::parse::fields(0, "2-4,6-8", &['-', ','])
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(nums, vec![2, 4, 6, 8]);
+/// ```
+pub fn fields<'a, 's, T: Int>(
+    index: usize,
+    line: &'a str,
+    separators: &'s [char],
+) -> Fields<'a, 's, T> {
+    Fields {
+        index,
+        line,
+        separators,
+        at: Some(0),
+        int: PhantomData,
+    }
+}
+
+/// The `N` integers in a line laid out like `template`, where each `{}` is
+/// an integer and everything else must appear exactly as written. Errors
+/// about the integers expect `what`, e.g. `"a section number"`. Use
+/// [`pattern_tuple`] for integers of different types.
+///
+/// ```
+/// let [a, b, c, d] =
+///     aoc::parse::pattern::<u8, 4>(0, "2-4,6-8", "{}-{},{}-{}", "a section").unwrap();
+/// assert_eq!((a, b, c, d), (2, 4, 6, 8));
+/// ```
+///
+/// # Panics
+///
+/// If `template` doesn't have exactly `N` placeholders.
+pub fn pattern<T: Int, const N: usize>(
+    index: usize,
+    line: &str,
+    template: &str,
+    what: &str,
+) -> Result<[T; N], ParseError> {
+    Ok(pattern_spans(index, line, template, what)?.map(|(n, _)| n))
+}
+
+/// Like [`pattern`], along with each integer's byte range in `line`, for
+/// reporting values that parse but make no sense.
+///
+/// # Panics
+///
+/// If `template` doesn't have exactly `N` placeholders.
+pub fn pattern_spans<T: Int, const N: usize>(
+    index: usize,
+    line: &str,
+    template: &str,
+    what: &str,
+) -> Result<[(T, Range<usize>); N], ParseError> {
+    let mut out: [Option<(T, Range<usize>)>; N] = std::array::from_fn(|_| None);
+    walk(index, line, template, &[T::SIGNED; N], |slot, span| {
+        out[slot] = Some((parse_as(index, line, span.clone(), what)?, span));
+        Ok(())
+    })?;
+    Ok(out.map(|n| n.expect("every slot is filled")))
+}
+
+/// A tuple of integers, possibly of different types, that
+/// [`pattern_tuple`] fills one `{}` each.
+pub trait Tuple: Sized {
+    #[doc(hidden)]
+    fn pattern(index: usize, line: &str, template: &str, what: &str) -> Result<Self, ParseError>;
+}
+
+macro_rules! tuple {
+    ($($t:ident $i:tt),*) => {
+        impl<$($t: Int),*> Tuple for ($($t,)*) {
+            fn pattern(
+                index: usize,
+                line: &str,
+                template: &str,
+                what: &str,
+            ) -> Result<Self, ParseError> {
+                let mut out = ($(None::<$t>,)*);
+                walk(index, line, template, &[$($t::SIGNED),*], |slot, span| {
+                    match slot {
+                        $($i => out.$i = Some(parse_as(index, line, span, what)?),)*
+                        _ => unreachable!("walk checks the placeholder count"),
+                    }
+                    Ok(())
+                })?;
+                Ok(($(out.$i.expect("every slot is filled"),)*))
+            }
+        }
+    };
+}
+
+tuple!(A 0);
+tuple!(A 0, B 1);
+tuple!(A 0, B 1, C 2);
+tuple!(A 0, B 1, C 2, D 3);
+tuple!(A 0, B 1, C 2, D 3, E 4);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Like [`pattern`], but fills a tuple, so each integer can have its own
+/// type.
+///
+/// ```
+/// let (x, y, id): (i32, i32, usize) =
+///     aoc::parse::pattern_tuple(0, "x=-3, y=14: #7", "x={}, y={}: #{}", "a number").unwrap();
+/// assert_eq!((x, y, id), (-3, 14, 7));
+/// ```
+///
+/// # Panics
+///
+/// If `template` doesn't have a placeholder for each element of the tuple.
+pub fn pattern_tuple<P: Tuple>(
+    index: usize,
+    line: &str,
+    template: &str,
+    what: &str,
+) -> Result<P, ParseError> {
+    P::pattern(index, line, template, what)
+}
+
+fn parse_as<T: Int>(
+    index: usize,
+    line: &str,
+    span: Range<usize>,
+    what: &str,
+) -> Result<T, ParseError> {
+    line[span.clone()]
+        .parse()
+        .map_err(|_| ParseError::at(index, line, span, what))
+}
+
+// Matches `line` against `template`, handing each placeholder's slot and
+// span to `field` in order. `signed` has one entry per placeholder.
+fn walk(
+    index: usize,
+    line: &str,
+    template: &str,
+    signed: &[bool],
+    mut field: impl FnMut(usize, Range<usize>) -> Result<(), ParseError>,
+) -> Result<(), ParseError> {
+    assert_eq!(
+        template.matches("{}").count(),
+        signed.len(),
+        "`{}` should have {} placeholders",
+        template,
+        signed.len()
+    );
+    let mut literals = template.split("{}");
+    let mut at = 0;
+    expect_literal(index, line, &mut at, literals.next().unwrap_or_default())?;
+    for (slot, (&signed, literal)) in signed.iter().zip(literals).enumerate() {
+        let span = int_span(line, at, signed, literal);
+        at = span.end;
+        field(slot, span)?;
+        expect_literal(index, line, &mut at, literal)?;
+    }
+    if at < line.len() {
+        return Err(ParseError::at(index, line, at..line.len(), "end of line"));
+    }
+    Ok(())
+}
+
+// The integer starting at `at`, or when there isn't one the word that is
+// there instead, up to whitespace or the start of the following literal.
+fn int_span(line: &str, at: usize, signed: bool, next: &str) -> Range<usize> {
+    let rest = &line[at..];
+    let sign = usize::from(signed && rest.starts_with('-'));
+    let digits = rest[sign..].bytes().take_while(u8::is_ascii_digit).count();
+    if digits > 0 {
+        return at..at + sign + digits;
+    }
+    let stop = next.chars().next();
+    let word = rest
+        .find(|c: char| c.is_whitespace() || Some(c) == stop)
+        .unwrap_or(rest.len());
+    at..at + word
+}
+
+fn expect_literal(
+    index: usize,
+    line: &str,
+    at: &mut usize,
+    literal: &str,
+) -> Result<(), ParseError> {
+    if line[*at..].starts_with(literal) {
+        *at += literal.len();
+        return Ok(());
+    }
+    let rest = &line[*at..];
+    let start = *at + rest.len() - rest.trim_start().len();
+    let word = literal.trim();
+    let len = match word.starts_with(char::is_alphanumeric) {
+        true => line[start..]
+            .find(|c: char| !c.is_alphanumeric())
+            .unwrap_or(line.len() - start),
+        false => 0,
+    };
+    let expected = match word.is_empty() {
+        true => literal,
+        false => word,
+    };
+    Err(ParseError::at(
+        index,
+        line,
+        start..start + len,
+        format!("`{}`", expected),
+    ))
+}
+
+#[cfg(test)]
+mod test_parse {
+    use crate::parse::*;
+
+    fn all<T: Int>(it: impl Iterator<Item = Result<T, ParseError>>) -> Vec<T> {
+        it.collect::<Result<_, _>>().unwrap()
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(all(ints::<usize>(0, "move 13 from 2 to 9")), vec![13, 2, 9]);
+        assert_eq!(all(ints::<i64>(0, "x=-3, y=14 z=--7")), vec![-3, 14, -7]);
+        assert_eq!(all(ints::<u32>(0, "2-4,6-8")), vec![2, 4, 6, 8]);
+        assert_eq!(all(ints::<i8>(0, "2-4")), vec![2, -4]);
+        assert!(all(ints::<u8>(0, "no numbers")).is_empty());
+        let err = ints::<u8>(4, "a 300").next().unwrap().unwrap_err();
+        assert_eq!(err.expected, "a number that fits in u8");
+        let span = err.span.unwrap();
+        assert_eq!((span.line, span.columns), (5, 2..5));
+    }
+
+    #[test]
+    fn test_exact_ints() {
+        assert_eq!(
+            exact_ints::<usize, 3>(0, "move 1 from 2 to 3"),
+            Ok([1, 2, 3])
+        );
+        let err = exact_ints::<usize, 3>(0, "move 1 from 2").unwrap_err();
+        assert_eq!(
+            (err.expected.as_str(), err.found.as_str()),
+            ("3 numbers", "2")
+        );
+        let err = exact_ints::<usize, 1>(0, "12 34").unwrap_err();
+        assert_eq!(
+            (err.expected.as_str(), err.found.as_str()),
+            ("only 1 number", "`34`")
+        );
+    }
+
+    #[test]
+    fn test_fields() {
+        assert_eq!(
+            all(fields::<i32>(0, "2-4,6-8", &['-', ','])),
+            vec![2, 4, 6, 8]
+        );
+        assert_eq!(all(fields::<u8>(0, "1,2;3", &[',', ';'])), vec![1, 2, 3]);
+        let err = fields::<i32>(2, "-1;;2", &[';'])
+            .nth(1)
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(err.span.unwrap().line, 3);
+    }
+
+    #[test]
+    fn test_pattern() {
+        let sections = |line| pattern::<u8, 4>(0, line, "{}-{},{}-{}", "a section");
+        assert_eq!(sections("2-4,6-8"), Ok([2, 4, 6, 8]));
+        let err = |line| {
+            let e = sections(line).unwrap_err();
+            (e.expected, e.found)
+        };
+        assert_eq!(err("2-4;6-8"), ("`,`".to_string(), "`;`".to_string()));
+        assert_eq!(err("2,4-6-8"), ("`-`".to_string(), "`,`".to_string()));
+        assert_eq!(err("2-4,x-8"), ("a section".to_string(), "`x`".to_string()));
+        assert_eq!(
+            err("2-4,6-300"),
+            ("a section".to_string(), "`300`".to_string())
+        );
+        assert_eq!(
+            err("2-4,6-"),
+            ("a section".to_string(), "end of line".to_string())
+        );
+        assert_eq!(
+            err("2-4,6-8x"),
+            ("end of line".to_string(), "`x`".to_string())
+        );
+
+        let moves = |line| pattern_spans::<usize, 3>(5, line, "move {} from {} to {}", "a number");
+        let [_, (from, span), _] = moves("move 12 from 3 to 1").unwrap();
+        assert_eq!((from, span), (3, 13..14));
+        let e = moves("move 3 from one to 1").unwrap_err();
+        assert_eq!(
+            (e.expected.as_str(), e.found.as_str()),
+            ("a number", "`one`")
+        );
+        assert_eq!(e.span.unwrap().line, 6);
+        let e = moves("shift 3 from 1 to 2").unwrap_err();
+        assert_eq!(
+            (e.expected.as_str(), e.found.as_str()),
+            ("`move`", "`shift`")
+        );
+        let e = moves("move 3 from 1").unwrap_err();
+        assert_eq!(
+            (e.expected.as_str(), e.found.as_str()),
+            ("`to`", "end of line")
+        );
+        assert_eq!(pattern::<i8, 2>(0, "-2--4", "{}-{}", "x"), Ok([-2, -4]));
+
+        let cave = |line| pattern_tuple::<(u8, i64)>(1, line, "{} at {}", "a coordinate");
+        assert_eq!(cave("3 at -40000000000"), Ok((3, -40000000000)));
+        let e = cave("-3 at 4").unwrap_err();
+        assert_eq!(
+            (e.expected.as_str(), e.found.as_str()),
+            ("a coordinate", "`-3`")
+        );
+        assert_eq!(pattern_tuple(0, "7", "{}", "x"), Ok((7u16,)));
+    }
+}
@@ -0,0 +1,139 @@
+use std::{
+    fmt,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+/// Where in an input a [`ParseError`] happened: a 1-based line number, the
+/// 0-based character columns of the offending text, and the line itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub columns: Range<usize>,
+    pub text: String,
+}
+
+/// An input that doesn't match what a day's parser expects. Renders as an
+/// annotated snippet of the offending line:
+///
+/// ```text
+/// expected A, B or C, found `D`
+///  --> input_p1.txt:3:1
+///   |
+/// 3 | D Y
+///   | ^ A, B or C
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub span: Option<Span>,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    /// An error about the input as a whole rather than any one line.
+    pub fn new(expected: impl fmt::Display, found: impl fmt::Display) -> Self {
+        ParseError {
+            file: None,
+            span: None,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    /// An error at byte range `bytes` of `text`, the line at 0-based index
+    /// `index` of the input. What was found is the text in that range.
+    pub fn at(index: usize, text: &str, bytes: Range<usize>, expected: impl fmt::Display) -> Self {
+        let start = bytes.start.min(text.len());
+        let end = bytes.end.clamp(start, text.len());
+        let found = match &text[start..end] {
+            "" if end == text.len() => "end of line".to_string(),
+            "" => format!("`{}`", &text[start..].chars().next().unwrap_or_default()),
+            s => format!("`{}`", s),
+        };
+        let column = text[..start].chars().count();
+        let width = text[start..end].chars().count().max(1);
+        ParseError {
+            file: None,
+            span: Some(Span {
+                line: index + 1,
+                columns: column..column + width,
+                text: text.to_string(),
+            }),
+            expected: expected.to_string(),
+            found,
+        }
+    }
+
+    /// Replaces what was found, e.g. with why a well-formed token is invalid.
+    pub fn found(mut self, found: impl fmt::Display) -> Self {
+        self.found = found.to_string();
+        self
+    }
+
+    pub fn in_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.file = Some(path.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {}, found {}", self.expected, self.found)?;
+        let file = self.file.as_ref().map(|p| p.display().to_string());
+        let Some(span) = &self.span else {
+            return match file {
+                Some(file) => write!(f, " in {}", file),
+                None => Ok(()),
+            };
+        };
+        let location = format!("{}:{}", span.line, span.columns.start + 1);
+        match file {
+            Some(file) => write!(f, "\n --> {}:{}", file, location)?,
+            None => write!(f, "\n --> line {}", location)?,
+        }
+        let gutter = " ".repeat(span.line.to_string().len());
+        write!(
+            f,
+            "\n{gutter} |\n{} | {}\n{gutter} | {}{} {}",
+            span.line,
+            span.text,
+            " ".repeat(span.columns.start),
+            "^".repeat(span.columns.len()),
+            self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod test_parse_error {
+    use crate::*;
+
+    #[test]
+    fn test_render_snippet() {
+        let err = ParseError::at(2, "D Y", 0..1, "A, B or C").in_file("input_p1.txt");
+        assert_eq!(err.found, "`D`");
+        assert_eq!(
+            err.to_string(),
+            "expected A, B or C, found `D`\n --> input_p1.txt:3:1\n  |\n3 | D Y\n  | ^ A, B or C"
+        );
+        let err = ParseError::at(9, "move 1 from é", 12..14, "a stack number");
+        assert_eq!(err.span.as_ref().unwrap().line, 10);
+        assert_eq!(
+            err.to_string(),
+            "expected a stack number, found `é`\n --> line 10:13\n   |\n10 | move 1 from é\n   |             ^ a stack number"
+        );
+    }
+
+    #[test]
+    fn test_found_at_end_of_line_and_without_span() {
+        let err = ParseError::at(0, "2-4,6", 5..5, "`-`");
+        assert_eq!(err.found, "end of line");
+        assert_eq!(err.span.unwrap().columns, 5..6);
+        let err = ParseError::new("2 sections", 3).in_file("x.txt");
+        assert_eq!(err.to_string(), "expected 2 sections, found 3 in x.txt");
+    }
+}
@@ -0,0 +1,420 @@
+use std::{
+    cell::Cell,
+    fmt,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Once,
+    },
+    time::{Duration, Instant},
+};
+
+use serde::{Serialize, Serializer};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    alloc::{self, AllocStats},
+    bench, Answer, AocError, Input, ParseError, Solution,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Answer,
+    #[serde(rename = "elapsed_ns", serialize_with = "bench::nanos")]
+    pub elapsed: Duration,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alloc: Option<AllocStats>,
+}
+
+/// The outcome of parsing an input once and solving the requested parts.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub day: u8,
+    pub parse: Duration,
+    pub parse_alloc: Option<AllocStats>,
+    pub parts: Vec<PartRun>,
+}
+
+/// Why [`run`] couldn't solve an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunError {
+    Parse(ParseError),
+    NoSuchPart(u8),
+}
+
+impl RunError {
+    pub fn in_file(self, path: impl AsRef<std::path::Path>) -> Self {
+        match self {
+            RunError::Parse(e) => RunError::Parse(e.in_file(path)),
+            e => e,
+        }
+    }
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Parse(e) => write!(f, "{}", e),
+            RunError::NoSuchPart(part) => write!(f, "there is no part {}, only 1 and 2", part),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+impl From<ParseError> for RunError {
+    fn from(e: ParseError) -> Self {
+        RunError::Parse(e)
+    }
+}
+
+pub fn run<S: Solution>(lines: &[String], parts: &[u8]) -> Result<Run, RunError> {
+    if let Some(&part) = parts.iter().find(|&&p| p != 1 && p != 2) {
+        return Err(RunError::NoSuchPart(part));
+    }
+    let _day = tracing::info_span!("day", day = S::DAY).entered();
+    let start = Instant::now();
+    let (parsed, parse_alloc) =
+        tracing::info_span!("parse").in_scope(|| alloc::measure(|| S::parse(lines)));
+    let parsed = parsed?;
+    let parse = start.elapsed();
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let _part = tracing::info_span!("part", part).entered();
+            let start = Instant::now();
+            let (answer, alloc) = alloc::measure(|| match part {
+                1 => S::part1(&parsed),
+                2 => S::part2(&parsed),
+                _ => unreachable!("parts are checked before parsing"),
+            });
+            let elapsed = start.elapsed();
+            tracing::debug!(%answer, ?elapsed, "solved");
+            PartRun {
+                part,
+                answer,
+                elapsed,
+                alloc,
+            }
+        })
+        .collect();
+    Ok(Run {
+        day: S::DAY,
+        parse,
+        parse_alloc,
+        parts,
+    })
+}
+
+/// An input's lines along with where they came from and a SHA-256 of its
+/// contents, so results can be tied to the exact input they were run on.
+#[derive(Debug, Clone)]
+pub struct Loaded {
+    pub path: PathBuf,
+    pub sha256: String,
+    pub lines: Vec<String>,
+}
+
+pub fn load(input: impl Into<Input>) -> Result<Loaded, AocError> {
+    let (path, text) = input.into().read()?;
+    let sha256 = Sha256::digest(text.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    let lines = crate::split_lines(path.clone(), &text)?;
+    Ok(Loaded {
+        path,
+        sha256,
+        lines,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    Error,
+}
+
+fn opt_nanos<S: Serializer>(d: &Option<Duration>, s: S) -> Result<S::Ok, S::Error> {
+    match d {
+        Some(d) => bench::nanos(d, s),
+        None => s.serialize_none(),
+    }
+}
+
+/// A machine-readable record of one run, or of why a day couldn't be run.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub input: Option<PathBuf>,
+    pub input_sha256: Option<String>,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(rename = "parse_ns", serialize_with = "opt_nanos")]
+    pub parse: Option<Duration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_alloc: Option<AllocStats>,
+    pub parts: Vec<PartRun>,
+}
+
+impl Report {
+    pub fn solved(loaded: &Loaded, run: Run) -> Self {
+        Report {
+            day: run.day,
+            input: Some(loaded.path.clone()),
+            input_sha256: Some(loaded.sha256.clone()),
+            status: Status::Ok,
+            error: None,
+            parse: Some(run.parse),
+            parse_alloc: run.parse_alloc,
+            parts: run.parts,
+        }
+    }
+
+    pub fn failed(day: u8, input: Option<PathBuf>, error: impl fmt::Display) -> Self {
+        Report {
+            day,
+            input,
+            input_sha256: None,
+            status: Status::Error,
+            error: Some(error.to_string()),
+            parse: None,
+            parse_alloc: None,
+            parts: vec![],
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("run reports always serialize")
+    }
+}
+
+/// A registered solution with its type erased, so a runner can hold every
+/// day in one list.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub run: fn(&[String], &[u8]) -> Result<Run, RunError>,
+    pub bench: fn(&[String], &bench::Config) -> Result<bench::Bench, ParseError>,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Self {
+        Day {
+            day: S::DAY,
+            run: run::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+}
+
+/// Maps `f` over `items` on a pool of worker threads, one per core, and
+/// returns the results in the order of `items` however the work finishes.
+/// Workers are as [`quiet_panics`] as the calling thread.
+pub fn parallel<T: Sync, U: Send>(items: &[T], f: impl Fn(&T) -> U + Sync) -> Vec<U> {
+    let quiet = QUIET.get();
+    let workers = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(items.len());
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, U)> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    QUIET.set(quiet);
+                    let mut done = vec![];
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        match items.get(i) {
+                            Some(item) => done.push((i, f(item))),
+                            None => return done,
+                        }
+                    }
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+            .collect()
+    });
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, u)| u).collect()
+}
+
+/// Runs `f`, turning a panic into an error carrying the panic message.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).map_err(|e| {
+        e.downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| e.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panicked".to_string())
+    })
+}
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+static QUIET_HOOK: Once = Once::new();
+
+/// Runs `f` with panics on this thread silenced, so panics that `f`
+/// [`catch`]es and reports itself don't also print a message each. The
+/// panic hook is wrapped once to skip quiet threads and never swapped back,
+/// so other threads keep their messages.
+pub fn quiet_panics<T>(f: impl FnOnce() -> T) -> T {
+    QUIET_HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                previous(info)
+            }
+        }));
+    });
+    let was = QUIET.replace(true);
+    let out = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+    QUIET.set(was);
+    out.unwrap_or_else(|e| std::panic::resume_unwind(e))
+}
+
+pub fn find(days: &[Day], day: u8) -> Option<Day> {
+    days.iter().find(|d| d.day == day).copied()
+}
+
+/// Formats a duration with a unit that keeps a few significant digits.
+pub fn fmt_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", d.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod test_runner {
+    use crate::runner::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 42;
+        type Parsed = Vec<usize>;
+
+        fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+            lines
+                .iter()
+                .enumerate()
+                .map(|(i, l)| {
+                    l.parse()
+                        .map_err(|_| ParseError::at(i, l, 0..l.len(), "a number"))
+                })
+                .collect()
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Answer {
+            parsed.iter().sum::<usize>().into()
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Answer {
+            parsed.iter().max().copied().unwrap_or(0).into()
+        }
+    }
+
+    #[test]
+    fn test_run_registered_day() {
+        let days = [Day::of::<Sum>()];
+        assert!(find(&days, 1).is_none());
+        let day = find(&days, 42).unwrap();
+        let lines = vec!["1".to_string(), "5".to_string()];
+        let res = (day.run)(&lines, &[2, 1]).unwrap();
+        assert_eq!(res.day, 42);
+        let answers: Vec<(u8, &Answer)> = res.parts.iter().map(|p| (p.part, &p.answer)).collect();
+        assert_eq!(answers, vec![(2, &Answer::Int(5)), (1, &Answer::Int(6))]);
+        match (day.run)(&["1".to_string(), "x".to_string()], &[1]) {
+            Err(RunError::Parse(e)) => assert_eq!(e.span.unwrap().line, 2),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        for part in [0, 3, 255] {
+            let err = (day.run)(&lines, &[1, part]).unwrap_err();
+            assert_eq!(err, RunError::NoSuchPart(part));
+        }
+        assert_eq!(fmt_duration(Duration::from_micros(1500)), "1.50ms");
+    }
+
+    #[test]
+    fn test_parallel_keeps_order() {
+        let delays: Vec<u64> = (0..16).map(|i| (16 - i) % 5).collect();
+        let out = parallel(&delays, |&ms| {
+            std::thread::sleep(Duration::from_millis(ms));
+            ms * 10
+        });
+        assert_eq!(out, delays.iter().map(|ms| ms * 10).collect::<Vec<_>>());
+        assert!(parallel(&[] as &[u8], |_| 0).is_empty());
+        assert_eq!(catch(|| 1), Ok(1));
+        assert_eq!(
+            catch(|| -> u8 { panic!("boom {}", 7) }),
+            Err("boom 7".to_string())
+        );
+        let quiet = quiet_panics(|| parallel(&[1, 2], |&n| catch(|| assert_ne!(n, 2))));
+        assert!(quiet[0].is_ok() && quiet[1].is_err());
+        assert_eq!(
+            catch(|| quiet_panics(|| panic!("escaped"))),
+            Err("escaped".to_string())
+        );
+        assert!(!QUIET.get());
+        assert_eq!(
+            quiet_panics(|| parallel(&[1, 2], |_| QUIET.get())),
+            [true, true]
+        );
+        assert_eq!(parallel(&[1], |_| QUIET.get()), [false]);
+    }
+
+    #[test]
+    fn test_report_json() {
+        let loaded = load(Input::Embedded {
+            path: "sum.txt".into(),
+            text: "1\n5\n",
+        })
+        .unwrap();
+        assert_eq!(
+            loaded.sha256,
+            "ffdb2b471864a2f3bbb7753ece957f6e8f0915538d88a495ac6f28d2583c44f1"
+        );
+        let report = Report::solved(&loaded, run::<Sum>(&loaded.lines, &[1]).unwrap());
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["day"], 42);
+        assert_eq!(json["status"], "ok");
+        assert_eq!(json["parts"][0]["answer"], 6);
+        assert!(json["parts"][0]["elapsed_ns"].is_u64());
+        assert!(json.get("error").is_none());
+
+        let failed = Report::failed(9, None, "day 9 is not implemented");
+        let json: serde_json::Value = serde_json::from_str(&failed.to_json()).unwrap();
+        assert_eq!(json["status"], "error");
+        assert_eq!(json["error"], "day 9 is not implemented");
+        assert!(json["parse_ns"].is_null());
+    }
+
+    #[test]
+    fn test_load_strips_crlf_and_bom() {
+        let loaded = load(Input::Embedded {
+            path: "sum.txt".into(),
+            text: "\u{feff}1\r\n5\r\n",
+        })
+        .unwrap();
+        assert_eq!(loaded.lines, vec!["1", "5"]);
+        assert_eq!(
+            run::<Sum>(&loaded.lines, &[1]).unwrap().parts[0].answer,
+            Answer::Int(6)
+        );
+    }
+}
@@ -0,0 +1,339 @@
+use std::{
+    fmt,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+pub fn package_name(day: u8) -> String {
+    format!("day_{:02}", day)
+}
+
+pub fn cargo_toml(day: u8) -> String {
+    format!(
+        r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc = {{ path = "../aoc" }}
+
+[dev-dependencies]
+test_support = {{ path = "../test_support" }}
+"#,
+        package_name(day)
+    )
+}
+
+pub fn lib_rs(day: u8) -> String {
+    format!(
+        r#"use aoc::{{Answer, ParseError, Solution}};
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    const DAY: u8 = {day};
+    type Parsed = Vec<String>;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {{
+        Ok(lines.to_vec())
+    }}
+
+    fn part1(_input: &Self::Parsed) -> Answer {{
+        Answer::from("unsolved")
+    }}
+
+    fn part2(_input: &Self::Parsed) -> Answer {{
+        Answer::from("unsolved")
+    }}
+}}
+
+#[cfg(test)]
+mod test_day_{day:02} {{
+    use crate::*;
+
+    aoc::answer_tests!(Day{day:02});
+}}
+"#
+    )
+}
+
+pub fn main_rs(day: u8) -> String {
+    format!(
+        r#"use aoc::Solution;
+use {package}::Day{day:02};
+
+fn main() {{
+    let input = aoc::day_input!();
+    let lines = aoc::or_exit(aoc::try_lines(input.clone()));
+    let parsed = aoc::or_exit(Day{day:02}::parse(&lines).map_err(|e| e.in_file(input.path())));
+    println!("p1: {{}}", Day{day:02}::part1(&parsed));
+    println!("p2: {{}}", Day{day:02}::part2(&parsed));
+}}
+"#,
+        package = package_name(day)
+    )
+}
+
+/// Inserts `line` into the line-aligned region `text[start..end]`, before
+/// the first peer line that sorts after it, or else at the end of the region.
+fn insert_sorted(
+    text: &str,
+    start: usize,
+    end: usize,
+    line: &str,
+    peer: impl Fn(&str) -> bool,
+) -> String {
+    let mut at = end;
+    let mut offset = start;
+    for existing in text[start..end].split_inclusive('\n') {
+        if peer(existing.trim()) && existing.trim() > line.trim() {
+            at = offset;
+            break;
+        }
+        offset += existing.len();
+    }
+    format!("{}{}{}", &text[..at], line, &text[at..])
+}
+
+/// Adds the day to the workspace `members`.
+pub fn add_member(workspace_toml: &str, day: u8) -> Option<String> {
+    let entry = format!("\"packages/{}\"", package_name(day));
+    if workspace_toml.contains(&entry) {
+        return None;
+    }
+    let start = workspace_toml.find("members = [\n")? + "members = [\n".len();
+    let end = start + workspace_toml[start..].find("]")?;
+    let line = format!("    {},\n", entry);
+    Some(insert_sorted(workspace_toml, start, end, &line, |l| {
+        l.starts_with('"')
+    }))
+}
+
+/// Adds the day as a path dependency of the cli, next to the other days.
+pub fn add_dependency(cli_toml: &str, day: u8) -> Option<String> {
+    let name = package_name(day);
+    if cli_toml.contains(&format!("\n{} = ", name)) {
+        return None;
+    }
+    let start = cli_toml.find("[dependencies]\n")? + "[dependencies]\n".len();
+    let end = cli_toml[start..]
+        .find("\n[")
+        .map(|i| start + i + 1)
+        .unwrap_or(cli_toml.len());
+    let line = format!("{} = {{ path = \"../{}\" }}\n", name, name);
+    Some(insert_sorted(cli_toml, start, end, &line, |l| {
+        l.starts_with("day_")
+    }))
+}
+
+/// Adds `Day::of::<day_NN::DayNN>()` to the cli registry's list.
+pub fn add_registration(registry: &str, day: u8) -> Option<String> {
+    let entry = format!("Day::of::<{}::Day{:02}>(),", package_name(day), day);
+    if registry.contains(&entry) {
+        return None;
+    }
+    let start = registry.find("vec![\n")? + "vec![\n".len();
+    let end = start + registry[start..].find("    ]")?;
+    let line = format!("        {}\n", entry);
+    Some(insert_sorted(registry, start, end, &line, |l| {
+        l.starts_with("Day::of::<")
+    }))
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    Unrecognised(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(p) => write!(f, "{} already exists", p.display()),
+            ScaffoldError::Unrecognised(p) => {
+                write!(
+                    f,
+                    "could not find where to register the day in {}",
+                    p.display()
+                )
+            }
+            ScaffoldError::Io(p, e) => write!(f, "{}: {}", p.display(), e),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// Files created and manifests edited by [`scaffold`], relative to the
+/// workspace.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Scaffolded {
+    pub created: Vec<PathBuf>,
+    pub updated: Vec<PathBuf>,
+}
+
+fn create(
+    workspace: &Path,
+    rel: PathBuf,
+    contents: &str,
+    done: &mut Scaffolded,
+) -> Result<(), ScaffoldError> {
+    let path = workspace.join(&rel);
+    let io_err = |e| ScaffoldError::Io(path.clone(), e);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(io_err)?;
+    }
+    match std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+    {
+        Ok(mut file) => {
+            file.write_all(contents.as_bytes()).map_err(io_err)?;
+            done.created.push(rel);
+            Ok(())
+        }
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(()),
+        Err(e) => Err(io_err(e)),
+    }
+}
+
+fn update(
+    workspace: &Path,
+    rel: PathBuf,
+    edit: impl Fn(&str) -> Option<String>,
+    registered: impl Fn(&str) -> bool,
+    done: &mut Scaffolded,
+) -> Result<(), ScaffoldError> {
+    let path = workspace.join(&rel);
+    let text = std::fs::read_to_string(&path).map_err(|e| ScaffoldError::Io(path.clone(), e))?;
+    match edit(&text) {
+        Some(updated) => {
+            std::fs::write(&path, updated).map_err(|e| ScaffoldError::Io(path.clone(), e))?;
+            done.updated.push(rel);
+            Ok(())
+        }
+        None if registered(&text) => Ok(()),
+        None => Err(ScaffoldError::Unrecognised(path)),
+    }
+}
+
+/// Creates `packages/day_NN` with a `Solution` skeleton and empty inputs,
+/// and registers it in the workspace and the cli. Existing files are never
+/// touched, so a half-finished scaffold can be re-run; a day that is already
+/// fully in place is reported as [`ScaffoldError::Exists`].
+pub fn scaffold(workspace: &Path, day: u8) -> Result<Scaffolded, ScaffoldError> {
+    let name = package_name(day);
+    let package = PathBuf::from("packages").join(&name);
+    let mut done = Scaffolded::default();
+    let files = [
+        ("Cargo.toml", cargo_toml(day)),
+        ("src/lib.rs", lib_rs(day)),
+        ("src/main.rs", main_rs(day)),
+        ("input_p1.txt", String::new()),
+        ("input_p1_demo.txt", String::new()),
+    ];
+    for (file, contents) in files {
+        create(workspace, package.join(file), &contents, &mut done)?;
+    }
+    let member = format!("\"packages/{}\"", name);
+    update(
+        workspace,
+        "Cargo.toml".into(),
+        |t| add_member(t, day),
+        |t| t.contains(&member),
+        &mut done,
+    )?;
+    update(
+        workspace,
+        "packages/cli/Cargo.toml".into(),
+        |t| add_dependency(t, day),
+        |t| t.contains(&format!("\n{} = ", name)),
+        &mut done,
+    )?;
+    update(
+        workspace,
+        "packages/cli/src/registry.rs".into(),
+        |t| add_registration(t, day),
+        |t| t.contains(&format!("<{}::", name)),
+        &mut done,
+    )?;
+    if done == Scaffolded::default() {
+        return Err(ScaffoldError::Exists(workspace.join(package)));
+    }
+    Ok(done)
+}
+
+#[cfg(test)]
+mod test_scaffold {
+    use crate::scaffold::*;
+
+    #[test]
+    fn test_registration_edits_are_sorted_and_idempotent() {
+        let ws = "[workspace]\nmembers = [\n    \"packages/aoc\",\n    \"packages/day_01\",\n    \"packages/day_10\",\n]\n";
+        let added = add_member(ws, 9).unwrap();
+        assert!(added
+            .contains("\"packages/day_01\",\n    \"packages/day_09\",\n    \"packages/day_10\","));
+        assert_eq!(add_member(&added, 9), None);
+
+        let cli =
+            "[dependencies]\naoc = { path = \"../aoc\" }\nday_01 = { path = \"../day_01\" }\n";
+        let added = add_dependency(cli, 2).unwrap();
+        assert!(added
+            .ends_with("day_01 = { path = \"../day_01\" }\nday_02 = { path = \"../day_02\" }\n"));
+        assert_eq!(add_dependency(&added, 2), None);
+
+        let registry = "pub fn days() -> Vec<Day> {\n    vec![\n        Day::of::<day_01::Day01>(),\n        Day::of::<day_03::Day03>(),\n    ]\n}\n";
+        let added = add_registration(registry, 2).unwrap();
+        assert!(added
+            .contains("Day01>(),\n        Day::of::<day_02::Day02>(),\n        Day::of::<day_03"));
+        let added = add_registration(&added, 4).unwrap();
+        assert!(added.ends_with("Day::of::<day_04::Day04>(),\n    ]\n}\n"));
+        assert_eq!(add_registration(&added, 4), None);
+    }
+
+    #[test]
+    fn test_scaffold_into_workspace() {
+        let ws = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&ws);
+        std::fs::create_dir_all(ws.join("packages/cli/src")).unwrap();
+        std::fs::write(
+            ws.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"packages/cli\",\n]\n",
+        )
+        .unwrap();
+        std::fs::write(ws.join("packages/cli/Cargo.toml"), "[dependencies]\n").unwrap();
+        std::fs::write(
+            ws.join("packages/cli/src/registry.rs"),
+            "pub fn days() -> Vec<Day> {\n    vec![\n    ]\n}\n",
+        )
+        .unwrap();
+
+        let done = scaffold(&ws, 9).unwrap();
+        assert_eq!(done.created.len(), 5);
+        assert_eq!(done.updated.len(), 3);
+        let lib = std::fs::read_to_string(ws.join("packages/day_09/src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day09"));
+        assert!(lib.contains("aoc::answer_tests!(Day09);"));
+        assert!(!lib.contains("todo!"));
+        let toml = std::fs::read_to_string(ws.join("packages/day_09/Cargo.toml")).unwrap();
+        assert!(toml.contains("[dev-dependencies]\ntest_support = { path = \"../test_support\" }"));
+
+        std::fs::write(ws.join("packages/day_09/src/lib.rs"), "// solved\n").unwrap();
+        assert!(matches!(scaffold(&ws, 9), Err(ScaffoldError::Exists(_))));
+        let lib = std::fs::read_to_string(ws.join("packages/day_09/src/lib.rs")).unwrap();
+        assert_eq!(lib, "// solved\n");
+
+        std::fs::remove_file(ws.join("packages/day_09/input_p1.txt")).unwrap();
+        let done = scaffold(&ws, 9).unwrap();
+        assert_eq!(
+            done.created,
+            vec![PathBuf::from("packages/day_09/input_p1.txt")]
+        );
+        assert!(done.updated.is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+use std::{fmt, ops::Deref};
+
+/// One blank-line-separated block of an input. `start` is the 0-based index
+/// of the block's first line within the whole input.
+#[derive(Debug, Clone, Copy)]
+pub struct Section<'a, S> {
+    pub index: usize,
+    pub start: usize,
+    pub lines: &'a [S],
+}
+
+impl<S> Section<'_, S> {
+    pub fn malformed(&self, reason: impl fmt::Display) -> SectionError {
+        SectionError::Malformed {
+            section: self.index,
+            line: self.start + 1,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl<S> Deref for Section<'_, S> {
+    type Target = [S];
+
+    fn deref(&self) -> &[S] {
+        self.lines
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SectionError {
+    Count {
+        expected: usize,
+        found: usize,
+    },
+    Malformed {
+        section: usize,
+        line: usize,
+        reason: String,
+    },
+}
+
+impl fmt::Display for SectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SectionError::Count { expected, found } => {
+                write!(f, "expected {} sections, found {}", expected, found)
+            }
+            SectionError::Malformed {
+                section,
+                line,
+                reason,
+            } => write!(
+                f,
+                "section {} (starting at line {}) is malformed: {}",
+                section + 1,
+                line,
+                reason
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SectionError {}
+
+/// Splits `lines` on empty lines. Consecutive empty lines produce empty
+/// sections, mirroring `slice::split`.
+pub fn sections<S: AsRef<str>>(lines: &[S]) -> Vec<Section<'_, S>> {
+    let mut start = 0;
+    lines
+        .split(|l| l.as_ref().is_empty())
+        .enumerate()
+        .map(|(index, block)| {
+            let section = Section {
+                index,
+                start,
+                lines: block,
+            };
+            start += block.len() + 1;
+            section
+        })
+        .collect()
+}
+
+/// Like [`sections`], but requires exactly `N` non-empty sections.
+pub fn exact_sections<const N: usize, S: AsRef<str>>(
+    lines: &[S],
+) -> Result<[Section<'_, S>; N], SectionError> {
+    let found = sections(lines);
+    if let Some(empty) = found.iter().find(|s| s.is_empty()) {
+        return Err(empty.malformed("section is empty"));
+    }
+    let count = found.len();
+    found.try_into().map_err(|_| SectionError::Count {
+        expected: N,
+        found: count,
+    })
+}
+
+#[cfg(test)]
+mod test_sections {
+    use crate::*;
+
+    #[test]
+    fn test_sections_track_offsets() {
+        let lines = ["a", "b", "", "c", "", "d", "e"];
+        let found = sections(&lines);
+        assert_eq!(found.len(), 3);
+        assert_eq!(found[1].lines, ["c"]);
+        assert_eq!(found[2].start, 5);
+        assert_eq!(&found[2][..], ["d", "e"]);
+    }
+
+    #[test]
+    fn test_exact_sections() {
+        let lines = ["a", "", "b"];
+        let [a, b] = exact_sections::<2, _>(&lines).unwrap();
+        assert_eq!((a.lines, b.lines), (&["a"][..], &["b"][..]));
+        assert_eq!(
+            exact_sections::<3, _>(&lines).unwrap_err(),
+            SectionError::Count {
+                expected: 3,
+                found: 2
+            }
+        );
+        let err = exact_sections::<3, _>(&["a", "", "", "b"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "section 2 (starting at line 3) is malformed: section is empty"
+        );
+    }
+}
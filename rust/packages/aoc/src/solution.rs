@@ -0,0 +1,211 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::ParseError;
+
+/// A puzzle answer. Parts return whatever they compute and it is normalised
+/// here, so tooling can print, compare and store any day the same way.
+///
+/// Equality is structural; check a computed answer against an expected one
+/// with [`Answer::matches`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// Rows of ASCII art, with trailing whitespace and blank rows trimmed.
+    Multiline(Vec<String>),
+}
+
+impl Answer {
+    pub fn multiline(text: &str) -> Self {
+        let mut rows: Vec<String> = text.lines().map(|l| l.trim_end().to_string()).collect();
+        while rows.last().is_some_and(|r| r.is_empty()) {
+            rows.pop();
+        }
+        Answer::Multiline(rows)
+    }
+
+    /// The answer as an integer, parsing text answers if need be.
+    pub fn as_int(&self) -> Option<i128> {
+        match self {
+            Answer::Int(n) => Some(*n),
+            Answer::Text(s) => s.parse().ok(),
+            Answer::Multiline(_) => None,
+        }
+    }
+
+    /// Whether this answer is `expected`, where an integer also matches text
+    /// that displays the same, so `Int(42)` matches an expected `"42"` read
+    /// from a file.
+    pub fn matches(&self, expected: &Answer) -> bool {
+        match (self, expected) {
+            (Answer::Int(_), Answer::Text(_)) | (Answer::Text(_), Answer::Int(_)) => {
+                self.to_string() == expected.to_string()
+            }
+            _ => self == expected,
+        }
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Multiline(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => f.write_str(s),
+            Answer::Multiline(rows) => f.write_str(&rows.join("\n")),
+        }
+    }
+}
+
+/// Text with more than one line is a [`Answer::Multiline`].
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        match s.trim_end_matches('\n').contains('\n') {
+            true => Answer::multiline(s),
+            false => Answer::Text(s.trim_end_matches('\n').to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        s.as_str().into()
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Answer::Text(c.to_string())
+    }
+}
+
+/// Like `From<&str>`, but text that is a whole integer becomes an
+/// [`Answer::Int`]. For answers read from plain text files.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Infallible> {
+        Ok(match s.trim().parse::<i128>() {
+            Ok(n) => Answer::Int(n),
+            Err(_) => s.into(),
+        })
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(v: $t) -> Self {
+                Answer::Int(v as i128)
+            }
+        })*
+    };
+}
+
+answer_from_int!(usize, u8, u16, u32, u64, isize, i8, i16, i32, i64);
+
+/// Integers serialize as numbers, text as a string and multi-line answers as
+/// an array of rows.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(n) => match i64::try_from(*n) {
+                Ok(n) => s.serialize_i64(n),
+                Err(_) => s.serialize_i128(*n),
+            },
+            Answer::Text(t) => s.serialize_str(t),
+            Answer::Multiline(rows) => rows.serialize(s),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an integer, a string or an array of rows")
+            }
+
+            fn visit_i64<E>(self, n: i64) -> Result<Answer, E> {
+                Ok(Answer::Int(n.into()))
+            }
+
+            fn visit_u64<E>(self, n: u64) -> Result<Answer, E> {
+                Ok(Answer::Int(n.into()))
+            }
+
+            fn visit_i128<E>(self, n: i128) -> Result<Answer, E> {
+                Ok(Answer::Int(n))
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<Answer, E> {
+                Ok(s.into())
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Answer, A::Error> {
+                let mut rows = vec![];
+                while let Some(row) = seq.next_element::<String>()? {
+                    rows.push(row);
+                }
+                Ok(Answer::multiline(&rows.join("\n")))
+            }
+        }
+
+        d.deserialize_any(Visitor)
+    }
+}
+
+/// One day's puzzle: parse the input once, then answer each part from it.
+pub trait Solution {
+    const DAY: u8;
+    type Parsed;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError>;
+    fn part1(parsed: &Self::Parsed) -> Answer;
+    fn part2(parsed: &Self::Parsed) -> Answer;
+}
+
+#[cfg(test)]
+mod test_solution {
+    use crate::*;
+
+    #[test]
+    fn test_answer_equality() {
+        assert_ne!(Answer::from(42usize), Answer::from("42"));
+        assert!(Answer::from(42usize).matches(&Answer::from("42")));
+        assert!(Answer::from("42").matches(&Answer::from(42usize)));
+        assert!(!Answer::from(42usize).matches(&Answer::from("42 ")));
+        assert!(!Answer::from("#").matches(&Answer::from("#\n#")));
+        assert_eq!("CMZ".parse::<Answer>().unwrap(), Answer::Text("CMZ".into()));
+        assert_eq!("-7\n".parse::<Answer>().unwrap(), Answer::Int(-7));
+        let art = Answer::from("#..#  \n####\n\n");
+        assert_eq!(art, Answer::Multiline(vec!["#..#".into(), "####".into()]));
+        assert_eq!(art.to_string(), "#..#\n####");
+        assert_eq!(art.as_int(), None);
+        assert_eq!(Answer::from("12").as_int(), Some(12));
+    }
+
+    #[test]
+    fn test_answer_serde() {
+        let answers = [
+            Answer::from(24933642u64),
+            Answer::from("TBVFVDZPN"),
+            Answer::from("#.\n.#"),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, r##"[24933642,"TBVFVDZPN",["#.",".#"]]"##);
+        let back: Vec<Answer> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, answers);
+        assert!(matches!(back[0], Answer::Int(_)));
+        assert!(back[2].is_multiline());
+    }
+}
@@ -0,0 +1,183 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::{Path, PathBuf},
+};
+
+use crate::{AocError, Input};
+
+/// Lazily yields the lines of an input. Line endings (`\n` or `\r\n`) and a
+/// leading UTF-8 BOM are stripped, and trailing empty lines are dropped to
+/// match [`crate::lines`].
+pub struct LinesIter {
+    path: PathBuf,
+    reader: Box<dyn BufRead>,
+    buf: String,
+    held: Option<String>,
+    pending_blank: usize,
+    started: bool,
+    yielded: bool,
+    done: bool,
+}
+
+/// Opens an input for streaming. A path of `-` reads from stdin.
+pub fn lines_iter(input: impl Into<Input>) -> Result<LinesIter, AocError> {
+    match input.into() {
+        Input::Embedded { path, text } => Ok(LinesIter::new(path, Box::new(text.as_bytes()))),
+        Input::Path(name) if name == Path::new("-") => {
+            Ok(LinesIter::new(name, Box::new(io::stdin().lock())))
+        }
+        Input::Path(name) => {
+            let path = crate::resolve(&name);
+            let file = File::open(&path).map_err(|e| AocError::from_io(&path, e))?;
+            Ok(LinesIter::new(path, Box::new(BufReader::new(file))))
+        }
+    }
+}
+
+impl LinesIter {
+    pub fn new(path: PathBuf, reader: Box<dyn BufRead>) -> Self {
+        LinesIter {
+            path,
+            reader,
+            buf: String::new(),
+            held: None,
+            pending_blank: 0,
+            started: false,
+            yielded: false,
+            done: false,
+        }
+    }
+
+    /// Hands `f` a plain `String` iterator, stopping at the first read error
+    /// and returning it in place of `f`'s result.
+    pub fn process<T>(
+        self,
+        f: impl FnOnce(&mut dyn Iterator<Item = String>) -> T,
+    ) -> Result<T, AocError> {
+        let mut error = None;
+        let mut shunt = Shunt {
+            inner: self,
+            error: &mut error,
+        };
+        let value = f(&mut shunt);
+        match error {
+            Some(e) => Err(e),
+            None => Ok(value),
+        }
+    }
+
+    pub fn first_line(mut self) -> Result<String, AocError> {
+        match self.next() {
+            Some(line) => line,
+            None => Err(AocError::Empty(self.path)),
+        }
+    }
+
+    fn read_line(&mut self) -> Option<Result<String, AocError>> {
+        self.buf.clear();
+        match self.reader.read_line(&mut self.buf) {
+            Ok(0) => None,
+            Ok(_) => {
+                let mut line = self.buf.as_str();
+                if !self.started {
+                    self.started = true;
+                    line = line.strip_prefix('\u{feff}').unwrap_or(line);
+                }
+                line = line.strip_suffix('\n').unwrap_or(line);
+                line = line.strip_suffix('\r').unwrap_or(line);
+                Some(Ok(line.to_string()))
+            }
+            Err(e) => Some(Err(AocError::from_io(&self.path, e))),
+        }
+    }
+}
+
+impl Iterator for LinesIter {
+    type Item = Result<String, AocError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if let Some(line) = self.held.take() {
+            if self.pending_blank > 0 {
+                self.pending_blank -= 1;
+                self.held = Some(line);
+                return Some(Ok(String::new()));
+            }
+            return Some(Ok(line));
+        }
+        loop {
+            match self.read_line() {
+                None => {
+                    self.done = true;
+                    if self.yielded {
+                        return None;
+                    }
+                    return Some(Err(AocError::Empty(self.path.clone())));
+                }
+                Some(Err(e)) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+                Some(Ok(line)) if line.is_empty() => self.pending_blank += 1,
+                Some(Ok(line)) => {
+                    self.yielded = true;
+                    self.held = Some(line);
+                    return self.next();
+                }
+            }
+        }
+    }
+}
+
+struct Shunt<'a> {
+    inner: LinesIter,
+    error: &'a mut Option<AocError>,
+}
+
+impl Iterator for Shunt<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        match self.inner.next()? {
+            Ok(line) => Some(line),
+            Err(e) => {
+                *self.error = Some(e);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_stream {
+    use crate::*;
+
+    fn iter_of(bytes: &'static [u8]) -> LinesIter {
+        LinesIter::new("mem".into(), Box::new(bytes))
+    }
+
+    #[test]
+    fn test_crlf_bom_and_trailing_blanks() {
+        let lines: Vec<String> = iter_of(b"\xef\xbb\xbf1000\r\n2000\r\n\r\n3000\n\n\n")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(lines, vec!["1000", "2000", "", "3000"]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            iter_of(b"\n\n").first_line(),
+            Err(AocError::Empty(_))
+        ));
+        let res = iter_of(b"ok\n\xff\xfe\nnever\n").process(|it| it.count());
+        assert!(matches!(res, Err(AocError::InvalidUtf8(_))));
+        assert!(matches!(
+            lines_iter("./nope.txt"),
+            Err(AocError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,398 @@
+use std::{
+    fmt,
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::client::{Client, ClientError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    AlreadySolved,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::RateLimited => "rate_limited",
+            Verdict::AlreadySolved => "already_solved",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        [
+            Verdict::Correct,
+            Verdict::Wrong,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::RateLimited,
+            Verdict::AlreadySolved,
+            Verdict::Unknown,
+        ]
+        .into_iter()
+        .find(|v| v.as_str() == s)
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What the server said about a submission, plus how long it asked us to
+/// wait before the next one.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    pub wait: Option<Duration>,
+}
+
+pub fn parse_response(html: &str) -> Response {
+    let text = html.to_lowercase();
+    let verdict = if text.contains("that's the right answer") {
+        Verdict::Correct
+    } else if text.contains("answer too recently") {
+        Verdict::RateLimited
+    } else if text.contains("not the right answer") && text.contains("too high") {
+        Verdict::TooHigh
+    } else if text.contains("not the right answer") && text.contains("too low") {
+        Verdict::TooLow
+    } else if text.contains("not the right answer") {
+        Verdict::Wrong
+    } else if text.contains("solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown
+    };
+    Response {
+        verdict,
+        wait: parse_wait(&text),
+    }
+}
+
+// "you have 1m 23s left to wait", "please wait one minute", "wait 5 minutes"
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some(idx) = text.find(" left to wait") {
+        let head = &text[..idx];
+        let start = head.rfind("you have ").map(|i| i + 9)?;
+        let secs = head[start..]
+            .split_whitespace()
+            .map(|part| {
+                let (n, scale) = match part.char_indices().last() {
+                    Some((i, 'h')) => (&part[..i], 3600),
+                    Some((i, 'm')) => (&part[..i], 60),
+                    Some((i, 's')) => (&part[..i], 1),
+                    _ => (part, 1),
+                };
+                n.parse::<u64>().unwrap_or(0) * scale
+            })
+            .sum();
+        return Some(Duration::from_secs(secs));
+    }
+    let idx = text.find("wait ")?;
+    let mut words = text[idx + 5..].split_whitespace();
+    let n = match words.next()? {
+        "one" | "a" => 1,
+        "two" => 2,
+        "five" => 5,
+        "ten" => 10,
+        w => w.parse().ok()?,
+    };
+    match words.next()? {
+        w if w.starts_with("minute") => Some(Duration::from_secs(n * 60)),
+        w if w.starts_with("second") => Some(Duration::from_secs(n)),
+        _ => None,
+    }
+}
+
+pub fn submit(client: &Client, day: u8, part: u8, answer: &str) -> Result<Response, ClientError> {
+    let url = format!("{}/answer", client.day_url(day));
+    let level = part.to_string();
+    let html = client.post_form(&url, &[("level", &level), ("answer", answer)])?;
+    Ok(parse_response(&html))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub at: u64,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub retry_at: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    Unloggable(String),
+    AlreadySolved(String),
+    KnownWrong(Verdict),
+    OutOfBounds { bound: String, verdict: Verdict },
+    Wait(Duration),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::Unloggable(a) => write!(f, "{:?} contains a tab or line break", a),
+            Refusal::AlreadySolved(a) => write!(f, "already solved with {}", a),
+            Refusal::KnownWrong(v) => write!(f, "answer was already submitted and was {}", v),
+            Refusal::OutOfBounds { bound, verdict } => {
+                write!(
+                    f,
+                    "{} was already {}, this answer is no better",
+                    bound, verdict
+                )
+            }
+            Refusal::Wait(d) => write!(f, "server asked us to wait another {}s", d.as_secs()),
+        }
+    }
+}
+
+/// Every submission we've made, one tab-separated line each:
+/// `at day part answer verdict retry_at`.
+pub struct SubmissionLog {
+    path: PathBuf,
+    pub entries: Vec<Entry>,
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// Each entry is one tab-separated line, so answers can't hold either.
+fn loggable(answer: &str) -> bool {
+    !answer.contains(['\t', '\n', '\r'])
+}
+
+impl SubmissionLog {
+    pub fn default_path() -> PathBuf {
+        crate::workspace_dir().join("submissions.log")
+    }
+
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(t) => t,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let entries = text
+            .lines()
+            .filter_map(|l| {
+                let f: Vec<&str> = l.split('\t').collect();
+                match f[..] {
+                    [at, day, part, answer, verdict, retry_at] => Some(Entry {
+                        at: at.parse().ok()?,
+                        day: day.parse().ok()?,
+                        part: part.parse().ok()?,
+                        answer: answer.to_string(),
+                        verdict: Verdict::parse(verdict)?,
+                        retry_at: retry_at.parse().ok(),
+                    }),
+                    _ => None,
+                }
+            })
+            .collect();
+        Ok(SubmissionLog {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    /// Decides locally whether submitting `answer` could possibly help.
+    pub fn check(&self, day: u8, part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+        if !loggable(answer) {
+            return Err(Refusal::Unloggable(answer.to_string()));
+        }
+        let prior = self
+            .entries
+            .iter()
+            .filter(|e| e.day == day && e.part == part);
+        let numeric = answer.parse::<i64>().ok();
+        for e in prior.clone() {
+            if e.verdict == Verdict::Correct {
+                return Err(Refusal::AlreadySolved(e.answer.clone()));
+            }
+            if e.answer == answer && e.verdict.is_wrong() {
+                return Err(Refusal::KnownWrong(e.verdict));
+            }
+            let bound = e.answer.parse::<i64>().ok();
+            let out_of_bounds = match (numeric, bound, e.verdict) {
+                (Some(n), Some(b), Verdict::TooHigh) => n >= b,
+                (Some(n), Some(b), Verdict::TooLow) => n <= b,
+                _ => false,
+            };
+            if out_of_bounds {
+                return Err(Refusal::OutOfBounds {
+                    bound: e.answer.clone(),
+                    verdict: e.verdict,
+                });
+            }
+        }
+        match prior.filter_map(|e| e.retry_at).max() {
+            Some(retry_at) if retry_at > now => {
+                Err(Refusal::Wait(Duration::from_secs(retry_at - now)))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn record(&mut self, entry: Entry) -> std::io::Result<()> {
+        if !loggable(&entry.answer) {
+            let reason = Refusal::Unloggable(entry.answer).to_string();
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                reason,
+            ));
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            entry.at,
+            entry.day,
+            entry.part,
+            entry.answer,
+            entry.verdict,
+            entry.retry_at.map(|t| t.to_string()).unwrap_or_default()
+        )?;
+        self.entries.push(entry);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_submit {
+    use crate::client::Client;
+    use crate::mock_server::MockServer;
+    use crate::submit::*;
+
+    #[test]
+    fn test_parse_response() {
+        let v = |html: &str| parse_response(html);
+        assert_eq!(
+            v("<p>That's the right answer!</p>").verdict,
+            Verdict::Correct
+        );
+        assert_eq!(
+            v("That's not the right answer; your answer is too high. Please wait one minute before trying again.",),
+            Response {
+                verdict: Verdict::TooHigh,
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            v("not the right answer; your answer is too low").verdict,
+            Verdict::TooLow
+        );
+        assert_eq!(
+            v("That's not the right answer. please wait 5 minutes before trying again."),
+            Response {
+                verdict: Verdict::Wrong,
+                wait: Some(Duration::from_secs(300))
+            }
+        );
+        assert_eq!(
+            v("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait."),
+            Response {
+                verdict: Verdict::RateLimited,
+                wait: Some(Duration::from_secs(83))
+            }
+        );
+        assert_eq!(
+            v("You don't seem to be solving the right level.").verdict,
+            Verdict::AlreadySolved
+        );
+        assert_eq!(parse_wait("you have 7é left to wait"), Some(Duration::ZERO));
+        assert_eq!(
+            parse_wait("you have 2h 1m left to wait"),
+            Some(Duration::from_secs(7260))
+        );
+        assert_eq!(parse_wait("you have  left to wait"), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_log_refusals() {
+        let path = std::env::temp_dir().join(format!("aoc_submissions_{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut log = SubmissionLog::load(&path).unwrap();
+        let entry = |answer: &str, verdict, retry_at| Entry {
+            at: 100,
+            day: 1,
+            part: 1,
+            answer: answer.to_string(),
+            verdict,
+            retry_at,
+        };
+        log.record(entry("500", Verdict::TooHigh, Some(160)))
+            .unwrap();
+        log.record(entry("10", Verdict::TooLow, None)).unwrap();
+
+        let log = SubmissionLog::load(&path).unwrap();
+        assert_eq!(log.entries.len(), 2);
+        assert_eq!(
+            log.check(1, 1, "500", 200),
+            Err(Refusal::KnownWrong(Verdict::TooHigh))
+        );
+        assert!(matches!(
+            log.check(1, 1, "600", 200),
+            Err(Refusal::OutOfBounds { .. })
+        ));
+        assert!(matches!(
+            log.check(1, 1, "5", 200),
+            Err(Refusal::OutOfBounds { .. })
+        ));
+        assert_eq!(
+            log.check(1, 1, "42", 130),
+            Err(Refusal::Wait(Duration::from_secs(30)))
+        );
+        assert_eq!(log.check(1, 1, "42", 200), Ok(()));
+        assert_eq!(log.check(1, 2, "500", 130), Ok(()));
+        assert_eq!(
+            log.check(1, 2, "5\t00", 200),
+            Err(Refusal::Unloggable("5\t00".to_string()))
+        );
+        let mut log = SubmissionLog::load(&path).unwrap();
+        assert!(log.record(entry("1\n2", Verdict::Wrong, None)).is_err());
+        assert_eq!(SubmissionLog::load(&path).unwrap().entries.len(), 2);
+    }
+
+    #[test]
+    fn test_submit_posts_form() {
+        let server = MockServer::start(vec![
+            (200, "<article><p>That's the right answer!</p></article>"),
+            (502, "Bad Gateway"),
+        ]);
+        let client = Client::new("abc").unwrap().with_base_url(&server.url);
+        let res = submit(&client, 7, 2, "24933642").unwrap();
+        assert_eq!(res.verdict, Verdict::Correct);
+        let req = &server.requests()[0];
+        assert!(req.starts_with("POST /2022/day/7/answer "));
+        assert!(req.ends_with("level=2&answer=24933642"));
+        let err = submit(&client, 7, 2, "1").unwrap_err();
+        assert!(err.to_string().starts_with("POST "), "{}", err);
+    }
+}
@@ -0,0 +1,79 @@
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use aoc::{
+    bench::{Bench, Config, Report, Stats},
+    runner::{self, fmt_duration, Day},
+};
+
+use crate::{registry, run, write_json, InputArgs};
+
+/// The short hash of the checked-out commit, if there is one.
+fn head_commit() -> Option<String> {
+    let out = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(aoc::workspace_dir())
+        .output()
+        .ok()?;
+    let hash = String::from_utf8(out.stdout).ok()?;
+    Some(hash.trim().to_string()).filter(|h| out.status.success() && !h.is_empty())
+}
+
+fn fmt_stats(s: &Stats) -> String {
+    format!(
+        "{} / {} / {}",
+        fmt_duration(s.min),
+        fmt_duration(s.median),
+        fmt_duration(s.p95)
+    )
+}
+
+fn print(b: &Bench) {
+    println!("day {:02}", b.day);
+    println!("  parse {}", fmt_stats(&b.parse));
+    println!("  p1    {}", fmt_stats(&b.part1));
+    println!("  p2    {}", fmt_stats(&b.part2));
+}
+
+pub fn run(
+    day: Option<u8>,
+    config: Config,
+    input: &InputArgs,
+    json: Option<PathBuf>,
+    label: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    let days: Vec<Day> = match day {
+        Some(day) => vec![run::lookup(day)?],
+        None => registry::days(),
+    };
+    // Keep stdout clean when the JSON goes there.
+    let table = json.as_deref() != Some(Path::new("-"));
+    if table {
+        println!("min / median / p95 over {} samples", config.samples);
+    }
+    let mut benches = vec![];
+    for solution in days {
+        let loaded = runner::load(input.resolve(solution.day))?;
+        let bench =
+            (solution.bench)(&loaded.lines, &config).map_err(|e| e.in_file(&loaded.path))?;
+        if table {
+            print(&bench);
+        }
+        benches.push(bench);
+    }
+    if let Some(path) = json {
+        let report = Report {
+            label: label.or_else(head_commit),
+            config,
+            benches,
+        };
+        write_json(&path, &report.to_json())?;
+        if table {
+            println!("wrote {}", path.display());
+        }
+    }
+    Ok(())
+}
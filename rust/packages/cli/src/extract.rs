@@ -0,0 +1,34 @@
+use std::{error::Error, path::PathBuf};
+
+use aoc::extract::{extract, write_day};
+
+use crate::RemoteArgs;
+
+pub fn run(day: u8, html: Option<PathBuf>, remote: &RemoteArgs) -> Result<(), Box<dyn Error>> {
+    let page = match html {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => {
+            let client = remote.client()?;
+            client.get(&client.day_url(day))?
+        }
+    };
+    let puzzle = extract(&page)?;
+    let dir = aoc::day_dir(day);
+    let written = write_day(&dir, day, &puzzle)?;
+    let demo = dir.join("input_p1_demo.txt");
+    if written.demo {
+        println!("wrote {}", demo.display());
+    } else {
+        println!("kept existing {}", demo.display());
+    }
+    for (i, answer) in puzzle.answers.iter().enumerate() {
+        let part = i as u8 + 1;
+        let status = if written.stubs.contains(&part) {
+            "added test"
+        } else {
+            "test exists"
+        };
+        println!("part {}: expect {} ({})", part, answer, status);
+    }
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use std::error::Error;
+
+use aoc::client::{cached_input, Fetched};
+
+use crate::RemoteArgs;
+
+pub fn run(day: u8, remote: &RemoteArgs) -> Result<(), Box<dyn Error>> {
+    let dest = aoc::day_dir(day).join("input_p1.txt");
+    match cached_input(|| remote.client(), day, &dest)? {
+        Fetched::Cached(p) => println!("cached: {}", p.display()),
+        Fetched::Downloaded(p) => println!("downloaded: {}", p.display()),
+    }
+    Ok(())
+}
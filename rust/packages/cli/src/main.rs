@@ -0,0 +1,210 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc::bench::Config;
+use aoc::client::{Client, ClientError, DEFAULT_BASE_URL};
+use clap::{Args, Parser, Subcommand};
+
+mod bench;
+mod extract;
+mod fetch;
+mod new;
+mod registry;
+mod run;
+mod submit;
+#[cfg(target_os = "linux")]
+mod watch;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 workspace tooling")]
+struct Cli {
+    /// Log debug events, or with -vv every trace event, to stderr
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day, or every day, and print answers with timings
+    Run {
+        #[arg(long, required_unless_present = "all")]
+        day: Option<u8>,
+        /// Solve every registered day in parallel and print a summary table
+        #[arg(long, conflicts_with_all = ["day", "input"])]
+        all: bool,
+        /// Solve the day on every *.txt in this directory, e.g. inputs/day_07,
+        /// checking each against its sibling .answer file
+        #[arg(long, requires = "day", conflicts_with_all = ["all", "input", "demo"])]
+        inputs: Option<PathBuf>,
+        /// Only solve this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Write a JSON report of the run to this file (`-` for stdout)
+        /// instead of printing answers
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+    /// Time parsing and both parts, reporting min/median/p95
+    Bench {
+        /// Only bench this day, instead of every registered one
+        #[arg(long)]
+        day: Option<u8>,
+        /// Untimed rounds before sampling
+        #[arg(long, default_value_t = Config::default().warmup)]
+        warmup: usize,
+        #[arg(long, default_value_t = Config::default().samples)]
+        samples: usize,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Also write the results as JSON to this file (`-` for stdout)
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// Identifies the run in the JSON, defaults to the current commit
+        #[arg(long, requires = "json")]
+        label: Option<String>,
+    },
+    /// Scaffold packages/day_NN and register it with the workspace and runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Re-run a day's tests and real input whenever its sources or inputs
+    /// change
+    #[cfg(target_os = "linux")]
+    Watch {
+        #[arg(long)]
+        day: u8,
+    },
+    /// Download a day's puzzle input into its package, unless already cached
+    Fetch {
+        #[arg(long)]
+        day: u8,
+        #[command(flatten)]
+        remote: RemoteArgs,
+    },
+    /// Pull the example input and expected answers out of a puzzle page into
+    /// the day's demo input and test stubs
+    Extract {
+        #[arg(long)]
+        day: u8,
+        /// A saved puzzle page, instead of fetching it
+        #[arg(long)]
+        html: Option<PathBuf>,
+        #[command(flatten)]
+        remote: RemoteArgs,
+    },
+    /// Submit a day's answer and record the verdict in submissions.log
+    Submit {
+        #[arg(long)]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Submit this instead of the answer computed by the day's binary
+        #[arg(long)]
+        answer: Option<String>,
+        #[command(flatten)]
+        remote: RemoteArgs,
+    },
+}
+
+#[derive(Args, Default)]
+pub struct InputArgs {
+    /// Read this input instead of the day's input_p1.txt (`-` for stdin)
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Use the day's input_p1_demo.txt
+    #[arg(long, conflicts_with = "input")]
+    demo: bool,
+}
+
+impl InputArgs {
+    pub fn resolve(&self, day: u8) -> aoc::Input {
+        match &self.input {
+            Some(path) => aoc::Input::Path(path.clone()),
+            None if self.demo => aoc::day_dir(day).join("input_p1_demo.txt").into(),
+            None => aoc::day_dir(day).join("input_p1.txt").into(),
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct RemoteArgs {
+    /// adventofcode.com session cookie
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+impl RemoteArgs {
+    pub fn client(&self) -> Result<Client, ClientError> {
+        let session = self.session.clone().ok_or(ClientError::MissingSession)?;
+        Ok(Client::new(session)?.with_base_url(&self.base_url))
+    }
+}
+
+/// Writes a JSON document to `dest`, or to stdout when it is `-`.
+pub fn write_json(dest: &Path, json: &str) -> std::io::Result<()> {
+    if dest == Path::new("-") {
+        writeln!(std::io::stdout(), "{}", json)
+    } else {
+        std::fs::write(dest, format!("{}\n", json))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    aoc::init_logging(cli.verbose);
+    let res = match cli.command {
+        Command::Run {
+            day: Some(day),
+            part,
+            inputs: Some(dir),
+            json,
+            ..
+        } => run::run_dir(day, part, &dir, json),
+        Command::Run {
+            day: Some(day),
+            part,
+            input,
+            json,
+            ..
+        } => run::run(day, part, &input, json),
+        Command::Run {
+            part, input, json, ..
+        } => run::run_all(part, &input, json),
+        Command::Bench {
+            day,
+            warmup,
+            samples,
+            input,
+            json,
+            label,
+        } => bench::run(day, Config { warmup, samples }, &input, json, label),
+        Command::New { day } => new::run(day),
+        #[cfg(target_os = "linux")]
+        Command::Watch { day } => watch::run(day),
+        Command::Fetch { day, remote } => fetch::run(day, &remote),
+        Command::Extract { day, html, remote } => extract::run(day, html, &remote),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            remote,
+        } => submit::run(day, part, answer, &remote),
+    };
+    match res {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
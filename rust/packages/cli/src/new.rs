@@ -0,0 +1,14 @@
+use std::error::Error;
+
+use aoc::scaffold::scaffold;
+
+pub fn run(day: u8) -> Result<(), Box<dyn Error>> {
+    let done = scaffold(&aoc::workspace_dir(), day)?;
+    for path in done.created {
+        println!("created {}", path.display());
+    }
+    for path in done.updated {
+        println!("updated {}", path.display());
+    }
+    Ok(())
+}
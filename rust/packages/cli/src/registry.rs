@@ -0,0 +1,15 @@
+use aoc::runner::Day;
+
+/// Every solved day. `aoc new` appends to this list.
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::of::<day_01::Day01>(),
+        Day::of::<day_02::Day02>(),
+        Day::of::<day_03::Day03>(),
+        Day::of::<day_04::Day04>(),
+        Day::of::<day_05::Day05>(),
+        Day::of::<day_06::Day06>(),
+        Day::of::<day_07::Day07>(),
+        Day::of::<day_08::Day08>(),
+    ]
+}
@@ -0,0 +1,302 @@
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use aoc::{
+    alloc::AllocStats,
+    answers::Answers,
+    corpus,
+    runner::{self, fmt_duration, Day, Loaded, Report, Run},
+    Answer,
+};
+
+use crate::{registry, write_json, InputArgs};
+
+pub fn lookup(day: u8) -> Result<Day, Box<dyn Error>> {
+    runner::find(&registry::days(), day)
+        .ok_or_else(|| format!("day {} is not implemented", day).into())
+}
+
+pub fn parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(p) => vec![p],
+        None => vec![1, 2],
+    }
+}
+
+/// Solves `day` on its resolved input.
+pub fn solve(
+    day: u8,
+    part: Option<u8>,
+    input: &InputArgs,
+) -> Result<(Loaded, Run), Box<dyn Error>> {
+    let solution = lookup(day)?;
+    let loaded = runner::load(input.resolve(day))?;
+    let run =
+        runner::quiet_panics(|| runner::catch(|| (solution.run)(&loaded.lines, &parts(part))))
+            .map_err(|e| format!("panicked: {}", e))?
+            .map_err(|e| e.in_file(&loaded.path))?;
+    Ok((loaded, run))
+}
+
+struct Row {
+    label: String,
+    part: String,
+    answer: String,
+    expected: String,
+    status: &'static str,
+    elapsed: String,
+}
+
+/// An answer as a table cell, which can't hold multi-line answers.
+fn cell(answer: &Answer) -> String {
+    match answer {
+        Answer::Multiline(rows) => format!("<{} rows>", rows.len()),
+        a => a.to_string(),
+    }
+}
+
+fn rows<'a>(
+    label: String,
+    res: &Result<(Loaded, Run), String>,
+    expected: impl Fn(u8) -> Option<&'a Answer>,
+) -> Vec<Row> {
+    let (_, run) = match res {
+        Ok(res) => res,
+        Err(e) => {
+            return vec![Row {
+                label,
+                part: "-".to_string(),
+                answer: e.lines().next().unwrap_or_default().to_string(),
+                expected: String::new(),
+                status: "error",
+                elapsed: String::new(),
+            }]
+        }
+    };
+    run.parts
+        .iter()
+        .map(|p| {
+            let expected = expected(p.part);
+            let status = match expected {
+                None => "ok",
+                Some(e) if p.answer.matches(e) => "pass",
+                Some(_) => "FAIL",
+            };
+            Row {
+                label: label.clone(),
+                part: p.part.to_string(),
+                answer: cell(&p.answer),
+                expected: expected.map(cell).unwrap_or_default(),
+                status,
+                elapsed: fmt_duration(p.elapsed),
+            }
+        })
+        .collect()
+}
+
+fn print_table(label: &'static str, rows: &[Row]) {
+    let header = [label, "part", "answer", "expected", "status", "time"];
+    let cells: Vec<[String; 6]> = rows
+        .iter()
+        .map(|r| {
+            [
+                r.label.clone(),
+                r.part.clone(),
+                r.answer.clone(),
+                r.expected.clone(),
+                r.status.to_string(),
+                r.elapsed.clone(),
+            ]
+        })
+        .collect();
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            cells
+                .iter()
+                .map(|c| c[i].chars().count())
+                .chain([header[i].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let line = |cols: Vec<&str>| {
+        let padded: Vec<String> = cols
+            .iter()
+            .zip(&widths)
+            .map(|(c, w)| format!("{:<w$}", c, w = w))
+            .collect();
+        println!("{}", padded.join("  ").trim_end());
+    };
+    line(header.to_vec());
+    for c in &cells {
+        line(c.iter().map(String::as_str).collect());
+    }
+}
+
+/// Solves every registered day on a thread pool. Answers on the real inputs
+/// are checked against `answers.toml`; any mismatch or error fails the run.
+pub fn run_all(
+    part: Option<u8>,
+    input: &InputArgs,
+    json: Option<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
+    let days = registry::days();
+    let answers = match input.demo {
+        true => None,
+        false => Answers::load().ok(),
+    };
+    let start = Instant::now();
+    let results = runner::quiet_panics(|| {
+        runner::parallel(&days, |solution| {
+            let loaded = runner::load(input.resolve(solution.day)).map_err(|e| e.to_string())?;
+            let run = runner::catch(|| (solution.run)(&loaded.lines, &parts(part)))
+                .map_err(|e| format!("panicked: {}", e))?
+                .map_err(|e| e.in_file(&loaded.path).to_string())?;
+            Ok((loaded, run))
+        })
+    });
+    let wall = start.elapsed();
+    let rows: Vec<Row> = days
+        .iter()
+        .zip(&results)
+        .flat_map(|(d, res)| {
+            let expected = |part| answers.as_ref().and_then(|a| a.get(d.day, part));
+            rows(format!("{:02}", d.day), res, expected)
+        })
+        .collect();
+    match json {
+        Some(dest) => {
+            let reports: Vec<Report> = days
+                .iter()
+                .zip(results)
+                .map(|(d, res)| match res {
+                    Ok((loaded, run)) => Report::solved(&loaded, run),
+                    Err(e) => Report::failed(d.day, Some(input.resolve(d.day).path().into()), e),
+                })
+                .collect();
+            write_json(&dest, &serde_json::to_string_pretty(&reports)?)?;
+        }
+        None => {
+            print_table("day", &rows);
+            print_snippets(&results);
+            println!("{} days in {}", days.len(), fmt_duration(wall));
+        }
+    }
+    check_rows(&rows)
+}
+
+/// Prints the annotated snippets of parse errors, which only get their first
+/// line in the table.
+fn print_snippets(results: &[Result<(Loaded, Run), String>]) {
+    for e in results.iter().filter_map(|r| r.as_ref().err()) {
+        if e.contains('\n') {
+            eprintln!("\n{}", e);
+        }
+    }
+}
+
+fn check_rows(rows: &[Row]) -> Result<(), Box<dyn Error>> {
+    let failed = rows
+        .iter()
+        .filter(|r| matches!(r.status, "FAIL" | "error"))
+        .count();
+    match failed {
+        0 => Ok(()),
+        n => Err(format!("{} failing row(s)", n).into()),
+    }
+}
+
+/// Solves `day` on every `*.txt` input in `dir`, checking each against its
+/// sibling `.answer` file. Panics are reported against the input that caused
+/// them rather than aborting the rest.
+pub fn run_dir(
+    day: u8,
+    part: Option<u8>,
+    dir: &Path,
+    json: Option<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
+    let solution = lookup(day)?;
+    let cases = corpus::cases(dir)?;
+    if cases.is_empty() {
+        return Err(format!("no *.txt inputs in {}", dir.display()).into());
+    }
+    let start = Instant::now();
+    let results = runner::quiet_panics(|| {
+        runner::parallel(&cases, |case| {
+            let loaded = runner::load(&case.input).map_err(|e| e.to_string())?;
+            let run = runner::catch(|| (solution.run)(&loaded.lines, &parts(part)))
+                .map_err(|e| format!("panicked: {}", e))?
+                .map_err(|e| e.in_file(&loaded.path).to_string())?;
+            Ok((loaded, run))
+        })
+    });
+    let wall = start.elapsed();
+    let rows: Vec<Row> = cases
+        .iter()
+        .zip(&results)
+        .flat_map(|(case, res)| rows(case.name(), res, |part| case.expected(part)))
+        .collect();
+    match json {
+        Some(dest) => {
+            let reports: Vec<Report> = cases
+                .iter()
+                .zip(results)
+                .map(|(case, res)| match res {
+                    Ok((loaded, run)) => Report::solved(&loaded, run),
+                    Err(e) => Report::failed(day, Some(case.input.clone()), e),
+                })
+                .collect();
+            write_json(&dest, &serde_json::to_string_pretty(&reports)?)?;
+        }
+        None => {
+            print_table("input", &rows);
+            print_snippets(&results);
+            println!(
+                "day {:02} on {} inputs in {}",
+                day,
+                cases.len(),
+                fmt_duration(wall)
+            );
+        }
+    }
+    check_rows(&rows)
+}
+
+fn fmt_alloc(stats: Option<AllocStats>) -> String {
+    stats.map(|s| format!(", {}", s)).unwrap_or_default()
+}
+
+pub fn run(
+    day: u8,
+    part: Option<u8>,
+    input: &InputArgs,
+    json: Option<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
+    let res = solve(day, part, input);
+    if let Some(dest) = json {
+        let report = match &res {
+            Ok((loaded, run)) => Report::solved(loaded, run.clone()),
+            Err(e) => Report::failed(day, Some(input.resolve(day).path().to_path_buf()), e),
+        };
+        write_json(&dest, &report.to_json())?;
+        return res.map(|_| ());
+    }
+    let (_, res) = res?;
+    println!(
+        "day {:02} parse: {}{}",
+        res.day,
+        fmt_duration(res.parse),
+        fmt_alloc(res.parse_alloc)
+    );
+    for p in res.parts {
+        let timing = format!("({}{})", fmt_duration(p.elapsed), fmt_alloc(p.alloc));
+        match p.answer.is_multiline() {
+            true => println!("p{}: {}\n{}", p.part, timing, p.answer),
+            false => println!("p{}: {} {}", p.part, p.answer, timing),
+        }
+    }
+    Ok(())
+}
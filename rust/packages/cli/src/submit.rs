@@ -0,0 +1,57 @@
+use std::error::Error;
+
+use aoc::submit::{now, submit, Entry, SubmissionLog, Verdict};
+
+use crate::{run, InputArgs, RemoteArgs};
+
+/// Solves the day on its real input and picks out `part`'s answer.
+fn computed_answer(day: u8, part: u8) -> Result<String, Box<dyn Error>> {
+    let (_, res) = run::solve(day, Some(part), &InputArgs::default())?;
+    let answer = &res.parts[0].answer;
+    if answer.is_multiline() {
+        return Err(format!(
+            "part {}'s answer is multi-line:\n{}\nread it and pass it with --answer",
+            part, answer
+        )
+        .into());
+    }
+    Ok(answer.to_string())
+}
+
+pub fn run(
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    remote: &RemoteArgs,
+) -> Result<(), Box<dyn Error>> {
+    let answer = match answer {
+        Some(a) => a,
+        None => computed_answer(day, part)?,
+    };
+    let mut log = SubmissionLog::load(&SubmissionLog::default_path())?;
+    if let Err(refusal) = log.check(day, part, &answer, now()) {
+        return Err(format!("not submitting {}: {}", answer, refusal).into());
+    }
+    let res = submit(&remote.client()?, day, part, &answer)?;
+    let at = now();
+    log.record(Entry {
+        at,
+        day,
+        part,
+        answer: answer.clone(),
+        verdict: res.verdict,
+        retry_at: res.wait.map(|w| at + w.as_secs()),
+    })?;
+    let wait = res
+        .wait
+        .map(|w| format!(" (wait {}s before retrying)", w.as_secs()))
+        .unwrap_or_default();
+    println!(
+        "day {} part {}: {} => {}{}",
+        day, part, answer, res.verdict, wait
+    );
+    match res.verdict {
+        Verdict::Correct | Verdict::AlreadySolved => Ok(()),
+        v => Err(format!("submission was {}", v).into()),
+    }
+}
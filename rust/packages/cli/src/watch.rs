@@ -0,0 +1,190 @@
+use std::{
+    error::Error,
+    ffi::OsStr,
+    io::ErrorKind,
+    path::Path,
+    process::{Command, Output},
+    thread,
+    time::{Duration, Instant},
+};
+
+use inotify::{Inotify, WatchMask};
+
+/// How long the tree must stay quiet after a change before re-running, so
+/// an editor's save or a multi-file change triggers one run.
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+#[derive(Debug, PartialEq, Eq)]
+enum Tests {
+    Passed(usize),
+    Failed(Vec<String>),
+    BuildFailed(String),
+}
+
+fn cargo(args: &[&str]) -> std::io::Result<Output> {
+    Command::new(env!("CARGO"))
+        .args(args)
+        .current_dir(aoc::workspace_dir())
+        .output()
+}
+
+/// The first compiler error, or else the last line of stderr.
+fn build_error(stderr: &str) -> String {
+    stderr
+        .lines()
+        .find(|l| l.starts_with("error"))
+        .or_else(|| stderr.lines().rev().find(|l| !l.trim().is_empty()))
+        .unwrap_or("failed")
+        .trim()
+        .to_string()
+}
+
+fn tests(stdout: &str, stderr: &str, success: bool) -> Tests {
+    let failed: Vec<String> = stdout
+        .lines()
+        .filter_map(|l| l.strip_prefix("test ")?.strip_suffix(" ... FAILED"))
+        .map(String::from)
+        .collect();
+    if !failed.is_empty() {
+        return Tests::Failed(failed);
+    }
+    if !success {
+        return Tests::BuildFailed(build_error(stderr));
+    }
+    let passed = stdout
+        .lines()
+        .filter_map(|l| l.strip_prefix("test result: ok. ")?.split(' ').next())
+        .filter_map(|n| n.parse::<usize>().ok())
+        .sum();
+    Tests::Passed(passed)
+}
+
+fn answers(output: &Output) -> Result<Vec<String>, String> {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        return Err(build_error(&String::from_utf8_lossy(&output.stderr)));
+    }
+    Ok(stdout
+        .lines()
+        .filter(|l| l.starts_with('p') && l.contains(": "))
+        .map(String::from)
+        .collect())
+}
+
+fn summary(day: u8, tests: &Tests, answers: &Result<Vec<String>, String>) -> String {
+    let tests = match tests {
+        Tests::Passed(n) => format!("tests ok ({})", n),
+        Tests::Failed(names) => format!("tests FAILED: {}", names.join(", ")),
+        Tests::BuildFailed(e) => format!("build FAILED: {}", e),
+    };
+    let answers = match answers {
+        Ok(lines) => lines.join("  "),
+        Err(e) => format!("run FAILED: {}", e),
+    };
+    format!("day {:02}: {}  |  {}", day, tests, answers)
+}
+
+fn check(day: u8) -> std::io::Result<String> {
+    let package = format!("day_{:02}", day);
+    let test = cargo(&["test", "--package", &package])?;
+    let tests = tests(
+        &String::from_utf8_lossy(&test.stdout),
+        &String::from_utf8_lossy(&test.stderr),
+        test.status.success(),
+    );
+    let answers = match tests {
+        Tests::BuildFailed(_) => Err("not built".to_string()),
+        _ => answers(&cargo(&[
+            "run",
+            "--quiet",
+            "--release",
+            "--package",
+            &package,
+        ])?),
+    };
+    Ok(summary(day, &tests, &answers))
+}
+
+fn relevant(name: Option<&OsStr>) -> bool {
+    let name = name.map(Path::new);
+    matches!(
+        name.and_then(Path::extension).and_then(OsStr::to_str),
+        Some("rs" | "txt" | "toml")
+    )
+}
+
+/// Blocks until something relevant changes, then until things go quiet.
+fn wait_for_change(inotify: &mut Inotify, buffer: &mut [u8]) -> std::io::Result<()> {
+    loop {
+        let events = inotify.read_events_blocking(buffer)?;
+        if events.into_iter().any(|e| relevant(e.name)) {
+            break;
+        }
+    }
+    loop {
+        thread::sleep(DEBOUNCE);
+        let quiet = match inotify.read_events(buffer) {
+            Ok(mut events) => events.next().is_none(),
+            Err(e) if e.kind() == ErrorKind::WouldBlock => true,
+            Err(e) => return Err(e),
+        };
+        if quiet {
+            return Ok(());
+        }
+    }
+}
+
+pub fn run(day: u8) -> Result<(), Box<dyn Error>> {
+    let dir = aoc::day_dir(day);
+    if !dir.is_dir() {
+        return Err(format!("{} does not exist, try `aoc new {}`", dir.display(), day).into());
+    }
+    let mut inotify = Inotify::init()?;
+    let mask = WatchMask::CLOSE_WRITE | WatchMask::CREATE | WatchMask::DELETE | WatchMask::MOVED_TO;
+    for watched in [dir.clone(), dir.join("src")] {
+        inotify.watches().add(&watched, mask)?;
+    }
+    println!("watching {} (ctrl-c to stop)", dir.display());
+    let mut buffer = [0; 4096];
+    loop {
+        let start = Instant::now();
+        let line = check(day)?;
+        println!("{}  [{:.1}s]", line, start.elapsed().as_secs_f64());
+        wait_for_change(&mut inotify, &mut buffer)?;
+    }
+}
+
+#[cfg(test)]
+mod test_watch {
+    use crate::watch::*;
+
+    #[test]
+    fn test_tests_summary() {
+        let ok = "running 2 tests\ntest a ... ok\ntest b ... ok\n\ntest result: ok. 2 passed; 0 failed\n\ntest result: ok. 1 passed; 0 failed\n";
+        assert_eq!(tests(ok, "", true), Tests::Passed(3));
+        let failed =
+            "test test_day_04::test_demo_input_p1 ... FAILED\ntest test_day_04::b ... ok\n";
+        assert_eq!(
+            tests(failed, "", false),
+            Tests::Failed(vec!["test_day_04::test_demo_input_p1".to_string()])
+        );
+        let stderr =
+            "   Compiling day_04\nerror[E0425]: cannot find value `x`\n  --> src/lib.rs:3:5\n";
+        assert_eq!(
+            tests("", stderr, false),
+            Tests::BuildFailed("error[E0425]: cannot find value `x`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_summary_line() {
+        let answers = Ok(vec!["p1: 2".to_string(), "p2: 4".to_string()]);
+        assert_eq!(
+            summary(4, &Tests::Passed(5), &answers),
+            "day 04: tests ok (5)  |  p1: 2  p2: 4"
+        );
+        assert!(relevant(Some(OsStr::new("lib.rs"))));
+        assert!(!relevant(Some(OsStr::new(".lib.rs.swp"))));
+        assert!(!relevant(None));
+    }
+}
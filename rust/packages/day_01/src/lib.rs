@@ -0,0 +1,95 @@
+use aoc::{Answer, ParseError, Solution};
+
+pub fn parse<I, S>(lines: I) -> Result<Vec<usize>, ParseError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut acc = vec![0];
+    for (i, l) in lines.into_iter().enumerate() {
+        let l = l.as_ref();
+        if l.is_empty() {
+            acc.push(0);
+        } else if let Some(last) = acc.last_mut() {
+            let [calories] = aoc::parse::pattern::<usize, 1>(i, l, "{}", "a calorie count")?;
+            *last += calories;
+        }
+    }
+    Ok(acc)
+}
+
+pub fn p1(elf_cals: &[usize]) -> usize {
+    *elf_cals.iter().max().unwrap()
+}
+
+pub fn p2(elf_cals: &mut [usize]) -> usize {
+    elf_cals.sort_unstable();
+    elf_cals.iter().rev().take(3).sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Parsed = Vec<usize>;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        parse(lines)
+    }
+
+    fn part1(elf_cals: &Self::Parsed) -> Answer {
+        p1(elf_cals).into()
+    }
+
+    fn part2(elf_cals: &Self::Parsed) -> Answer {
+        p2(&mut elf_cals.clone()).into()
+    }
+}
+
+#[cfg(test)]
+mod test_day_01 {
+    use crate::*;
+
+    aoc::answer_tests!(Day01);
+
+    aoc::demo_test!(test_demo_input, Day01, part1, "input_p1_demo.txt", 24000);
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = test_support::day_01::generate(&mut test_support::rng(seed), 200);
+            let mut totals: Vec<usize> = input
+                .split("\n\n")
+                .map(|block| {
+                    block
+                        .lines()
+                        .map(|l| l.parse::<usize>().unwrap())
+                        .sum::<usize>()
+                })
+                .collect();
+            totals.sort_unstable_by(|a, b| b.cmp(a));
+            let parsed = Day01::parse(&test_support::lines(&input)).unwrap();
+            let top = Day01::part1(&parsed).as_int().unwrap();
+            let top_three = Day01::part2(&parsed).as_int().unwrap();
+            assert_eq!(top, totals[0] as i128);
+            assert!(top_three >= top);
+            assert_eq!(top_three, totals[..3].iter().sum::<usize>() as i128);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse(["1000", "", "20O0"]).unwrap_err();
+        let span = err.span.as_ref().unwrap();
+        assert_eq!((span.line, span.columns.clone()), (3, 2..4));
+        assert_eq!(
+            (err.expected.as_str(), err.found.as_str()),
+            ("end of line", "`O0`")
+        );
+        let err = parse(["x1000"]).unwrap_err();
+        assert_eq!(
+            (err.expected.as_str(), err.found.as_str()),
+            ("a calorie count", "`x1000`")
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use aoc::{Answer, ParseError, Solution};
+
+#[derive(Clone)]
+enum Rpc {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+pub enum Outcome {
+    Win,
+    Draw,
+    Lose,
+}
+
+pub struct Round(Rpc, Rpc);
+
+impl Round {
+    pub fn outcome(&self) -> Outcome {
+        match (&self.1, &self.0) {
+            (Rpc::Rock, Rpc::Paper) => Outcome::Lose,
+            (Rpc::Rock, Rpc::Scissors) => Outcome::Win,
+            (Rpc::Paper, Rpc::Scissors) => Outcome::Lose,
+            (Rpc::Paper, Rpc::Rock) => Outcome::Win,
+            (Rpc::Scissors, Rpc::Rock) => Outcome::Lose,
+            (Rpc::Scissors, Rpc::Paper) => Outcome::Win,
+            _ => Outcome::Draw,
+        }
+    }
+    pub fn score(&self) -> usize {
+        let outcome_score = match self.outcome() {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        };
+        let hand_score = match self.1 {
+            Rpc::Rock => 1,
+            Rpc::Paper => 2,
+            Rpc::Scissors => 3,
+        };
+        outcome_score + hand_score
+    }
+}
+
+trait Score {
+    fn score(&self) -> usize;
+}
+
+impl Score for Vec<Round> {
+    fn score(&self) -> usize {
+        self.iter().map(|r| r.score()).sum()
+    }
+}
+/// The two letters of the round on line `index`, e.g. `A Y`, with the byte
+/// offset of the second one.
+fn letters(index: usize, line: &str) -> Result<(char, char, usize), ParseError> {
+    let chars = line.chars().take(3).collect::<Vec<char>>();
+    match &chars[..] {
+        &[a, ' ', b] => Ok((a, b, a.len_utf8() + 1)),
+        _ => Err(ParseError::at(
+            index,
+            line,
+            0..line.len(),
+            "two letters like `A Y`",
+        )),
+    }
+}
+
+fn opponent(index: usize, line: &str, a: char) -> Result<Rpc, ParseError> {
+    match a {
+        'A' => Ok(Rpc::Rock),
+        'B' => Ok(Rpc::Paper),
+        'C' => Ok(Rpc::Scissors),
+        c => Err(ParseError::at(index, line, 0..c.len_utf8(), "A, B or C")),
+    }
+}
+
+pub fn parse_rounds(lines: &[String]) -> Result<Vec<Round>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, l)| {
+            let (a, b, at) = letters(i, l)?;
+            let p1 = opponent(i, l, a)?;
+            let p2 = match b {
+                'X' => Rpc::Rock,
+                'Y' => Rpc::Paper,
+                'Z' => Rpc::Scissors,
+                c => return Err(ParseError::at(i, l, at..at + c.len_utf8(), "X, Y or Z")),
+            };
+            Ok(Round(p1, p2))
+        })
+        .collect()
+}
+
+pub fn parse_strategy(lines: &[String]) -> Result<Vec<Round>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, l)| {
+            let (a, b, at) = letters(i, l)?;
+            let p1 = opponent(i, l, a)?;
+            let outcome = match b {
+                'X' => Outcome::Lose,
+                'Y' => Outcome::Draw,
+                'Z' => Outcome::Win,
+                c => return Err(ParseError::at(i, l, at..at + c.len_utf8(), "X, Y or Z")),
+            };
+            let p2: Rpc = match (&p1, outcome) {
+                (m, Outcome::Draw) => m.clone(),
+                (Rpc::Paper, Outcome::Win) => Rpc::Scissors,
+                (Rpc::Rock, Outcome::Win) => Rpc::Paper,
+                (Rpc::Scissors, Outcome::Win) => Rpc::Rock,
+                (Rpc::Paper, Outcome::Lose) => Rpc::Rock,
+                (Rpc::Rock, Outcome::Lose) => Rpc::Scissors,
+                (Rpc::Scissors, Outcome::Lose) => Rpc::Paper,
+            };
+            Ok(Round(p1, p2))
+        })
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Parsed = (Vec<Round>, Vec<Round>);
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        Ok((parse_rounds(lines)?, parse_strategy(lines)?))
+    }
+
+    fn part1((rounds, _): &Self::Parsed) -> Answer {
+        rounds.score().into()
+    }
+
+    fn part2((_, strategy): &Self::Parsed) -> Answer {
+        strategy.score().into()
+    }
+}
+
+#[cfg(test)]
+mod test_day_02 {
+    use crate::*;
+
+    aoc::answer_tests!(Day02);
+
+    aoc::demo_test!(test_demo_input, Day02, part1, "input_p1_demo.txt", 15);
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = test_support::day_02::generate(&mut test_support::rng(seed), 500);
+            let parsed = Day02::parse(&test_support::lines(&input)).unwrap();
+            for answer in [Day02::part1(&parsed), Day02::part2(&parsed)] {
+                let score = answer.as_int().unwrap();
+                assert!((500..=500 * 9).contains(&score));
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let lines = test_support::lines(
+            "A Y
+B W
+",
+        );
+        let err = parse_rounds(&lines).err().unwrap();
+        assert_eq!(
+            (err.expected.as_str(), err.found.as_str()),
+            ("X, Y or Z", "`W`")
+        );
+        assert!(err.to_string().ends_with("2 | B W\n  |   ^ X, Y or Z"));
+        let err = parse_strategy(&test_support::lines(
+            "AY
+",
+        ))
+        .err()
+        .unwrap();
+        assert_eq!(err.expected, "two letters like `A Y`");
+    }
+}
@@ -0,0 +1,102 @@
+use aoc::{Answer, ParseError, Solution};
+use std::collections::HashSet;
+
+pub fn priority(c: char) -> usize {
+    let code = c as u32;
+    match code {
+        65..=90 => ((code as usize) - 65) + 27,
+        97..=122 => (code as usize) - 96,
+        _ => panic!("invalid priority"),
+    }
+}
+
+#[derive(Clone)]
+pub struct Sack(String);
+
+impl Sack {
+    pub fn compartment_collider(&self) -> char {
+        let (c1, c2) = self.0.split_at(self.0.len() / 2);
+        let c1_chars: HashSet<char> = HashSet::from_iter(c1.chars());
+        let c2_chars: HashSet<char> = HashSet::from_iter(c2.chars());
+        let common = c1_chars.intersection(&c2_chars);
+        let c = common
+            .take(1)
+            .next()
+            .expect("couldn't find intersecting char");
+        *c
+    }
+}
+
+struct Group(Sack, Sack, Sack);
+
+impl Group {
+    pub fn group_item(&self) -> char {
+        let c1_chars: HashSet<char> = HashSet::from_iter(self.0 .0.chars());
+        let c2_chars: HashSet<char> = HashSet::from_iter(self.1 .0.chars());
+        let c3_chars: HashSet<char> = HashSet::from_iter(self.2 .0.chars());
+        let tmp = c1_chars.intersection(&c2_chars);
+        let tmp_set: HashSet<char> = HashSet::from_iter(tmp.copied());
+        let common = tmp_set.intersection(&c3_chars);
+        let c = common
+            .take(1)
+            .next()
+            .expect("couldn't find intersecting char");
+        *c
+    }
+}
+fn parse(lines: &[String]) -> Vec<Sack> {
+    lines.iter().map(|v| Sack(String::to_owned(v))).collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Parsed = Vec<Sack>;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        Ok(parse(lines))
+    }
+
+    fn part1(sacks: &Self::Parsed) -> Answer {
+        sacks
+            .iter()
+            .map(Sack::compartment_collider)
+            .map(priority)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(sacks: &Self::Parsed) -> Answer {
+        sacks
+            .chunks(3)
+            .map(|x| match x {
+                [a, b, c] => Group(a.clone(), b.clone(), c.clone()),
+                _ => panic!("whoops"),
+            })
+            .map(|x| x.group_item())
+            .map(priority)
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test_day_03 {
+    use crate::*;
+
+    aoc::answer_tests!(Day03);
+
+    aoc::demo_test!(test_demo_input_p2, Day03, part2, "input_p1_demo.txt", 70);
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let sacks = test_support::day_03::generate(&mut test_support::rng(seed), 50);
+            let parsed = Day03::parse(&test_support::lines(&sacks.input)).unwrap();
+            let sum = |items: &[char]| items.iter().copied().map(priority).sum::<usize>();
+            assert_eq!(Day03::part1(&parsed), sum(&sacks.shared).into());
+            assert_eq!(Day03::part2(&parsed), sum(&sacks.badges).into());
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use aoc::{Answer, ParseError, Solution};
+use std::collections::HashSet;
+
+#[derive(Clone)]
+pub struct ElfCleanPair(HashSet<u8>, HashSet<u8>);
+
+impl ElfCleanPair {
+    /// Parses a pair like `2-4,6-8`, the line at `index` of the input.
+    pub fn from_line(index: usize, line: &str) -> Result<Self, ParseError> {
+        let [a, b, c, d] =
+            aoc::parse::pattern::<u8, 4>(index, line, "{}-{},{}-{}", "a section number")?;
+        Ok(Self((a..=b).collect(), (c..=d).collect()))
+    }
+
+    pub fn has_full_overlap(&self) -> bool {
+        self.0.difference(&self.1).count() == 0 || self.1.difference(&self.0).count() == 0
+    }
+
+    pub fn has_partial_overlap(&self) -> bool {
+        self.0.intersection(&self.1).count() > 0 || self.1.intersection(&self.0).count() > 0
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Parsed = Vec<ElfCleanPair>;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, l)| ElfCleanPair::from_line(i, l))
+            .collect()
+    }
+
+    fn part1(elf_pairs: &Self::Parsed) -> Answer {
+        elf_pairs
+            .iter()
+            .filter(|x| x.has_full_overlap())
+            .count()
+            .into()
+    }
+
+    fn part2(elf_pairs: &Self::Parsed) -> Answer {
+        elf_pairs
+            .iter()
+            .filter(|x| x.has_partial_overlap())
+            .count()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test_day_04 {
+    use crate::*;
+
+    aoc::answer_tests!(Day04);
+
+    aoc::demo_test!(test_demo_input_p1, Day04, part1, "input_p1_demo.txt", 2);
+    aoc::demo_test!(test_demo_input_p2, Day04, part2, "input_p1_demo.txt", 4);
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = test_support::day_04::generate(&mut test_support::rng(seed), 300);
+            let parsed = Day04::parse(&test_support::lines(&input)).unwrap();
+            let count = |a: Answer| a.as_int().unwrap();
+            let (full, partial) = (count(Day04::part1(&parsed)), count(Day04::part2(&parsed)));
+            assert!(full <= partial && partial <= 300);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = |line: &str| ElfCleanPair::from_line(0, line).err().unwrap();
+        assert_eq!(err("2-4;6-8").found, "`;`");
+        assert_eq!(err("2-4,6-").found, "end of line");
+        assert_eq!(err("2-4,6-300").expected, "a section number");
+        assert_eq!(err("2-4,6-8x").expected, "end of line");
+        let lines = test_support::lines("2-4,6-8\n2-3,x-5\n");
+        let span = Day04::parse(&lines).err().unwrap().span.unwrap();
+        assert_eq!((span.line, span.columns), (2, 4..5));
+    }
+}
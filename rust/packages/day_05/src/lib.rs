@@ -0,0 +1,261 @@
+// use std::collections::HashSet;
+
+use aoc::{Answer, ParseError, SectionError, Solution};
+
+#[derive(Clone)]
+pub struct CrateZone(Vec<Vec<char>>);
+
+impl CrateZone {
+    pub fn apply_bulk_move(&mut self, mv: &(usize, usize, usize)) {
+        tracing::trace!(count = mv.0, from = mv.1, to = mv.2, "bulk move");
+        let src = mv.1 - 1;
+        let dest = mv.2 - 1;
+        let mut to_move: Vec<char> = vec![];
+        let mut i = 1;
+        while i <= mv.0 {
+            to_move.push(self.0[src].pop().expect("char missing from stack"));
+            i += 1;
+        }
+        to_move.reverse();
+        self.0[dest].append(&mut to_move);
+    }
+
+    pub fn apply_move(&mut self, mv: &(usize, usize, usize)) {
+        tracing::trace!(count = mv.0, from = mv.1, to = mv.2, "move");
+        let src = mv.1 - 1;
+        let dest = mv.2 - 1;
+        let mut i = 1;
+        while i <= mv.0 {
+            let popped = self.0[src].pop().expect("char missing from stack");
+            self.0[dest].push(popped);
+            i += 1;
+        }
+    }
+    pub fn tops(&self) -> String {
+        String::from_iter(self.0.iter().filter_map(|col| col.last()))
+    }
+
+    /// Reads the stacks drawn in `lines`, the first of which is line `start`
+    /// of the input. The number row under the drawing sets how many stacks
+    /// there are.
+    pub fn from_lines(start: usize, lines: &[String]) -> Result<Self, ParseError> {
+        let Some((numbers, rows)) = lines.split_last() else {
+            return Err(ParseError::new("a drawing of crate stacks", "no lines"));
+        };
+        let index = start + rows.len();
+        let labels = aoc::parse::ints::<usize>(index, numbers).collect::<Result<Vec<_>, _>>()?;
+        if labels.is_empty() || labels.iter().enumerate().any(|(i, &n)| n != i + 1) {
+            let expected = "stack numbers counting up from 1";
+            return Err(ParseError::at(index, numbers, 0..numbers.len(), expected));
+        }
+        let mut cols: Vec<Vec<char>> = vec![vec![]; labels.len()];
+        for (i, line) in rows.iter().enumerate().rev() {
+            let chars: Vec<(usize, char)> = line.char_indices().collect();
+            for (col, chunk) in chars.chunks(4).enumerate() {
+                let at = chunk[0].0;
+                let end = chunk.last().map_or(at, |&(b, c)| b + c.len_utf8());
+                let cell: Vec<char> = chunk.iter().map(|&(_, c)| c).collect();
+                let c = match cell[..] {
+                    [' ', ' ', ' '] | [' ', ' ', ' ', ' '] => continue,
+                    ['[', c, ']'] | ['[', c, ']', ' '] => c,
+                    _ => {
+                        let expected = "a crate like `[A]` or a gap";
+                        return Err(ParseError::at(start + i, line, at..end, expected));
+                    }
+                };
+                match cols.get_mut(col) {
+                    Some(stack) => stack.push(c),
+                    None => {
+                        let expected = format!("at most {} stacks", labels.len());
+                        return Err(ParseError::at(start + i, line, at..end, expected));
+                    }
+                }
+            }
+        }
+        Ok(CrateZone(cols))
+    }
+}
+
+/// Parses `move N from A to B` lines, the first of which is line `start` of
+/// the input, checking each move against the stacks of `zone` as it stands
+/// after the moves before it.
+fn parse_moves(
+    start: usize,
+    lines: &[String],
+    zone: &CrateZone,
+) -> Result<Vec<(usize, usize, usize)>, ParseError> {
+    let mut heights: Vec<usize> = zone.0.iter().map(Vec::len).collect();
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, l)| {
+            let [(count, count_at), (from, from_at), (to, to_at)] =
+                aoc::parse::pattern_spans(start + i, l, "move {} from {} to {}", "a number")?;
+            for (stack, at) in [(from, from_at), (to, to_at)] {
+                if stack == 0 || stack > heights.len() {
+                    let expected = format!("a stack from 1 to {}", heights.len());
+                    return Err(ParseError::at(start + i, l, at, expected));
+                }
+            }
+            if count > heights[from - 1] {
+                let expected =
+                    format!("at most the {} crates on stack {}", heights[from - 1], from);
+                return Err(ParseError::at(start + i, l, count_at, expected));
+            }
+            heights[from - 1] -= count;
+            heights[to - 1] += count;
+            Ok((count, from, to))
+        })
+        .collect()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Parsed = (CrateZone, Vec<(usize, usize, usize)>);
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        let [crate_lines, move_lines] = aoc::exact_sections(lines).map_err(|e| {
+            let expected = "the crates and the moves separated by a blank line";
+            match e {
+                SectionError::Count { found: 1, .. } => ParseError::new(expected, "1 section"),
+                SectionError::Count { found, .. } => {
+                    ParseError::new(expected, format!("{} sections", found))
+                }
+                SectionError::Malformed {
+                    section,
+                    line,
+                    reason,
+                } => ParseError::new(
+                    format!(
+                        "section {} (starting at line {}) to be well-formed",
+                        section + 1,
+                        line
+                    ),
+                    reason,
+                ),
+            }
+        })?;
+        let cratezone = CrateZone::from_lines(crate_lines.start, &crate_lines)?;
+        let moves = parse_moves(move_lines.start, &move_lines, &cratezone)?;
+        Ok((cratezone, moves))
+    }
+
+    fn part1((cratezone, moves): &Self::Parsed) -> Answer {
+        let mut cratezone = cratezone.clone();
+        moves.iter().for_each(|mv| cratezone.apply_move(mv));
+        cratezone.tops().into()
+    }
+
+    fn part2((cratezone, moves): &Self::Parsed) -> Answer {
+        let mut cratezone = cratezone.clone();
+        moves.iter().for_each(|mv| cratezone.apply_bulk_move(mv));
+        cratezone.tops().into()
+    }
+}
+
+#[cfg(test)]
+mod test_day_05 {
+    use crate::*;
+
+    aoc::answer_tests!(Day05);
+
+    aoc::demo_test!(test_demo_input_p1, Day05, part1, "input_p1_demo.txt", "CMZ");
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let stacks = 2 + seed as usize % 8;
+            let moves = 1 + seed as usize * 10;
+            let input = test_support::day_05::generate(&mut test_support::rng(seed), stacks, moves);
+            let parsed = Day05::parse(&test_support::lines(&input)).unwrap();
+            for tops in [Day05::part1(&parsed), Day05::part2(&parsed)] {
+                let tops = tops.to_string();
+                assert!(tops.len() <= stacks);
+                assert!(tops.chars().all(|c| c.is_ascii_uppercase()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove 3 from one to 3\n";
+        let err = Day05::parse(&test_support::lines(input)).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "expected a number, found `one`\n --> line 6:13\n  |\n6 | move 3 from one to 3\n  |             ^^^ a number"
+        );
+        let zone = CrateZone(vec![vec!['A'], vec![]]);
+        let err = |line: &str| parse_moves(0, &[line.to_string()], &zone).unwrap_err();
+        assert_eq!(err("move 1 from 2").found, "end of line");
+        assert_eq!(err("move 1 from 2 to 1 now").found, "` now`");
+        assert_eq!(err("shift 1 from 2 to 1").expected, "`move`");
+        let err = Day05::parse(&test_support::lines("[A]\n 1\n"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "expected the crates and the moves separated by a blank line, found 1 section"
+        );
+        let err = Day05::parse(&test_support::lines(
+            "[A]\n 1\n\nmove 1 from 1 to 1\n\n[B]\n",
+        ))
+        .err()
+        .unwrap();
+        assert_eq!(err.found, "3 sections");
+    }
+
+    #[test]
+    fn test_invalid_stacks() {
+        let parse = |input: &str| Day05::parse(&test_support::lines(input));
+        let ten = format!(
+            "[A]{}[J]\n 1   2   3   4   5   6   7   8   9   10\n\nmove 1 from 10 to 2\n",
+            " ".repeat(33)
+        );
+        assert_eq!(Day05::part1(&parse(&ten).unwrap()), "AJ".into());
+        let err = |input: &str| {
+            let e = parse(input).err().unwrap();
+            let span = e.span.unwrap();
+            (span.line, span.text[span.columns].to_string(), e.expected)
+        };
+        let demo = "    [D]\n[N] [C]\n 1   2\n\n";
+        let stack = "a stack from 1 to 2".to_string();
+        assert_eq!(
+            err(&format!("{}move 1 from 0 to 1\n", demo)),
+            (5, "0".to_string(), stack.clone())
+        );
+        assert_eq!(
+            err(&format!("{}move 1 from 1 to 2\nmove 1 from 2 to 3\n", demo)),
+            (6, "3".to_string(), stack)
+        );
+        assert_eq!(
+            err(&format!("{}move 1 from 1 to 2\nmove 2 from 1 to 2\n", demo)),
+            (
+                6,
+                "2".to_string(),
+                "at most the 0 crates on stack 1".to_string()
+            )
+        );
+        assert_eq!(
+            err("[A] [B] [C]\n 1   2\n\nmove 1 from 1 to 2\n"),
+            (1, "[C]".to_string(), "at most 2 stacks".to_string())
+        );
+        assert_eq!(
+            err("[A] B\n 1   2\n\nmove 1 from 1 to 2\n"),
+            (
+                1,
+                "B".to_string(),
+                "a crate like `[A]` or a gap".to_string()
+            )
+        );
+        assert_eq!(
+            err("[A]\n 2\n\nmove 1 from 1 to 1\n"),
+            (
+                2,
+                " 2".to_string(),
+                "stack numbers counting up from 1".to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use aoc::{Answer, ParseError, Solution};
+#[cfg(any(test, feature = "differential"))]
+pub mod reference;
+
+/// How many characters are processed once the last `num_unique` are all
+/// distinct. Slides a window of byte counts along the stream, so inputs are
+/// expected to be ASCII.
+pub fn solve(input: &str, num_unique: usize) -> Option<usize> {
+    let bytes = input.as_bytes();
+    let mut counts = [0usize; 256];
+    let mut distinct = 0;
+    for (i, &b) in bytes.iter().enumerate() {
+        if counts[b as usize] == 0 {
+            distinct += 1;
+        }
+        counts[b as usize] += 1;
+        if i >= num_unique {
+            let old = bytes[i - num_unique] as usize;
+            counts[old] -= 1;
+            if counts[old] == 0 {
+                distinct -= 1;
+            }
+        }
+        if distinct == num_unique {
+            return Some(i + 1);
+        }
+    }
+    None
+}
+
+fn marker(input: &str, num_unique: usize) -> Answer {
+    solve(input, num_unique)
+        .unwrap_or_else(|| panic!("no {} distinct characters in a row", num_unique))
+        .into()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Parsed = String;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        Ok(lines.first().cloned().unwrap_or_default())
+    }
+
+    fn part1(input: &Self::Parsed) -> Answer {
+        marker(input, 4)
+    }
+
+    fn part2(input: &Self::Parsed) -> Answer {
+        marker(input, 14)
+    }
+}
+
+#[cfg(test)]
+mod test_day_05 {
+    use crate::*;
+
+    aoc::answer_tests!(Day06);
+
+    aoc::demo_test!(test_demo_input_p1, Day06, part1, "input_p1_demo.txt", 7);
+    aoc::demo_test!(test_demo_input_p2a, Day06, part2, "input_p2_demo_a.txt", 19);
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let signal = test_support::day_06::generate(&mut test_support::rng(seed), 4096);
+            let parsed = Day06::parse(&test_support::lines(&signal.input)).unwrap();
+            assert_eq!(Day06::part1(&parsed), signal.packet.into());
+            assert_eq!(Day06::part2(&parsed), signal.message.into());
+        }
+    }
+
+    #[cfg(feature = "differential")]
+    #[test]
+    fn test_differential_against_reference() {
+        use test_support::differential::{assert_agree, shrink_text};
+        let streams = (0..300).map(|seed| {
+            let mut rng = test_support::rng(seed);
+            let len = 17 + seed as usize * 3;
+            let signal = test_support::day_06::generate(&mut rng, len);
+            signal.input.trim_end().to_string()
+        });
+        for n in [4, 14] {
+            assert_agree(
+                streams.clone(),
+                |s| reference::solve(s, n),
+                |s| solve(s, n),
+                |s| shrink_text(s),
+            );
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! The original solver, kept as an oracle for the optimised one.
+
+use std::collections::HashSet;
+
+pub fn solve(input: &str, num_unique: usize) -> Option<usize> {
+    let chars = &input.chars().collect::<Vec<char>>();
+    chars
+        .windows(num_unique)
+        .enumerate()
+        .find(|(_, x)| {
+            let set: HashSet<char> = x.iter().copied().collect();
+            set.len() == num_unique
+        })
+        .map(|(i, _)| i + num_unique)
+}
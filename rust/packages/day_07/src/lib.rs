@@ -0,0 +1,325 @@
+use aoc::{Answer, Solution};
+use nom::{
+    self,
+    branch::alt,
+    bytes::complete::{tag, take_while},
+    character::is_digit,
+    error::ParseError,
+    IResult,
+};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub enum File {
+    File(usize, String),
+    Dir(String),
+}
+
+impl File {
+    #[allow(dead_code)]
+    pub fn size(&self) -> usize {
+        match self {
+            File::File(size, _) => *size,
+            File::Dir(_) => 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Input {
+    File(File),
+    Cd(String),
+    Ls,
+}
+
+#[derive(Debug)]
+pub enum DirStatKind {
+    File(usize),
+    Dir(DirStat),
+}
+#[derive(Debug)]
+pub struct DirStat {
+    current_dir: String,
+    pub slug: String,
+    pub path: String,
+    files: HashMap<String, DirStatKind>,
+}
+
+impl Default for DirStat {
+    fn default() -> DirStat {
+        DirStat {
+            current_dir: "/".to_string(),
+            slug: "".to_string(),
+            path: "/".to_string(),
+            files: HashMap::default(),
+        }
+    }
+}
+
+impl DirStat {
+    pub fn dirs(&self) -> HashMap<&str, &DirStat> {
+        self.files
+            .iter()
+            .fold(HashMap::default(), |mut acc, (key, value)| match value {
+                DirStatKind::File(_) => acc,
+                DirStatKind::Dir(stat) => {
+                    acc.insert(key, stat);
+                    acc
+                }
+            })
+    }
+    pub fn new_relative(path: &str, slug: &str) -> Self {
+        DirStat {
+            slug: slug.to_string(),
+            path: format!("{}/{}", path, slug).replace("//", "/"),
+            ..DirStat::default()
+        }
+    }
+    // case: / => self
+    // case: /a => a
+    // case: /a/b => b
+    // given a/b/c, get the associated DirStat
+    pub fn get_dirstat(&mut self, dir: &str) -> &mut Self {
+        if dir.is_empty() || dir == "/" {
+            return self;
+        }
+        let simple_dir = if dir.starts_with("/") {
+            dir.chars().skip(1).collect()
+        } else {
+            dir.to_owned()
+        };
+        let mut is_first = true;
+        let normalized = simple_dir.split("/").fold(vec![], |mut acc, it| {
+            if it == ".." {
+                acc.pop();
+            } else {
+                acc.push(it.to_string());
+            }
+            acc
+        });
+        let (first_slugs, rest_slugs): (Vec<_>, Vec<_>) = normalized.iter().partition(|_| {
+            if is_first {
+                is_first = false;
+                true
+            } else {
+                is_first
+            }
+        });
+
+        match (first_slugs.first(), rest_slugs.len()) {
+            (None, _) => self,
+            (Some(&slug), _) => {
+                match self
+                    .files
+                    .entry(slug.to_string())
+                    .or_insert(DirStatKind::Dir(DirStat::new_relative(&self.path, slug)))
+                {
+                    DirStatKind::Dir(stat) => stat.get_dirstat(
+                        &rest_slugs
+                            .iter()
+                            .map(|&s| s.to_owned())
+                            .collect::<Vec<String>>()
+                            .join("/"),
+                    ),
+                    _ => panic!("only directory expected"),
+                }
+            }
+        }
+    }
+
+    pub fn absolute_of_rel_cwd(&self, rel: &str) -> String {
+        if rel.starts_with("/") {
+            rel.to_string()
+        } else {
+            format!("{}/{}", &self.current_dir, &rel).replace("//", "/")
+        }
+    }
+    pub fn play_output(&mut self, input: Input) {
+        match input {
+            Input::Cd(p) => {
+                let stat = self.get_dirstat(&self.absolute_of_rel_cwd(&p));
+                self.current_dir = stat.path.clone();
+                tracing::debug!(dir = %p, cwd = %self.current_dir, "cd");
+            }
+            Input::File(File::Dir(dir)) => {
+                let stat = self.get_dirstat(&self.absolute_of_rel_cwd(&dir));
+                tracing::trace!(dir = %dir, path = %stat.path, "dir");
+            }
+            Input::File(File::File(a, b)) => {
+                tracing::trace!(size = a, name = %b, "file");
+                let dir = self.current_dir.clone();
+                let stat = self.get_dirstat(&dir);
+                stat.files.insert(b, DirStatKind::File(a));
+            }
+            Input::Ls => {
+                // let stat = self.get_dirstat(&self.current_dir);
+            }
+        };
+    }
+    pub fn total_file_size(&self) -> usize {
+        self.files
+            .values()
+            .map(|ftype| match ftype {
+                DirStatKind::File(size) => *size,
+                DirStatKind::Dir(d) => d.total_file_size(),
+            })
+            .sum()
+    }
+    pub fn total_file_size_p1(&self) -> usize {
+        let my_total = self.total_file_size();
+        let contribution = if my_total <= 100000 { my_total } else { 0 };
+        self.dirs()
+            .iter()
+            .map(|(_, &stat)| stat.total_file_size_p1())
+            .sum::<usize>()
+            + contribution
+    }
+
+    pub fn walk_p2(&self, min_size: usize, (name, size): (String, usize)) -> (String, usize) {
+        let mut best_size = size;
+        let mut best_name = name;
+        for (_name, stat) in self.dirs() {
+            let local_min = stat.total_file_size();
+            if local_min >= min_size {
+                let (a, b) = stat.walk_p2(min_size, (stat.path.clone(), local_min));
+                if b < best_size {
+                    best_name = a;
+                    best_size = b;
+                }
+            }
+        }
+        (best_name, best_size)
+    }
+
+    pub fn p2(&self) -> (String, usize) {
+        let capacity = 70000000_usize;
+        let max_usable = capacity - 30000000;
+        let my_size = self.total_file_size();
+        let needs_to_free = my_size - max_usable;
+        self.walk_p2(needs_to_free, (self.path.clone(), my_size))
+    }
+}
+
+fn parse_ls(i: &str) -> IResult<&str, Input> {
+    let (i, _) = tag("$ ls")(i)?;
+    Ok((i, Input::Ls))
+}
+
+fn parse_cd(i: &str) -> IResult<&str, Input> {
+    let (i, _) = tag("$ cd ")(i)?;
+    Ok((i, Input::Cd(i.to_string())))
+}
+fn parse_dir(i: &str) -> IResult<&str, Input> {
+    let (i, _) = tag("dir ")(i)?;
+    Ok((i, Input::File(File::Dir(i.to_string()))))
+}
+
+fn parse_file_bytes(i: &[u8]) -> IResult<&[u8], Input> {
+    let (fname, digits) = take_while(is_digit)(i)?;
+    let size = String::from_utf8_lossy(digits)
+        .parse::<usize>()
+        .map_err(|_| {
+            nom::Err::Error(nom::error::Error::from_error_kind(
+                i,
+                nom::error::ErrorKind::Digit,
+            ))
+        })?;
+    Ok((
+        i,
+        Input::File(File::File(
+            size,
+            String::from_utf8_lossy(fname).trim().to_string(),
+        )),
+    ))
+}
+
+fn parse_file(i: &str) -> IResult<&str, Input> {
+    match parse_file_bytes(i.as_bytes()) {
+        Ok((_, y)) => Ok((i, y)),
+        Err(_) => Err(nom::Err::Error(nom::error::Error::from_error_kind(
+            i,
+            nom::error::ErrorKind::AlphaNumeric,
+        ))),
+    }
+}
+
+/// Parses one line of terminal output, the line at `index` of the session.
+fn parse_shell(index: usize, line: &str) -> Result<Input, aoc::ParseError> {
+    alt((parse_cd, parse_ls, parse_dir, parse_file))(line)
+        .map(|(_, input)| input)
+        .map_err(|_| {
+            let word = line.find(' ').unwrap_or(line.len());
+            let span = match line.starts_with('$') {
+                true => (word + 1).min(line.len())..line.len(),
+                false => 0..word,
+            };
+            let expected = match line.starts_with('$') {
+                true => "`cd` or `ls`",
+                false => "`dir` or a file size",
+            };
+            aoc::ParseError::at(index, line, span, expected)
+        })
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Parsed = DirStat;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, aoc::ParseError> {
+        let mut dir_stat = DirStat::default();
+        for (i, line) in lines.iter().enumerate() {
+            dir_stat.play_output(parse_shell(i, line)?);
+        }
+        Ok(dir_stat)
+    }
+
+    fn part1(dir_stat: &Self::Parsed) -> Answer {
+        dir_stat.total_file_size_p1().into()
+    }
+
+    fn part2(dir_stat: &Self::Parsed) -> Answer {
+        dir_stat.p2().1.into()
+    }
+}
+
+#[cfg(test)]
+mod test_day_07 {
+    use crate::*;
+
+    aoc::answer_tests!(Day07);
+
+    aoc::demo_test!(test_demo_input_p1, Day07, part1, "input_p1_demo.txt", 95437);
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let (input, small_dirs) =
+                test_support::day_07::generate_with_total(&mut test_support::rng(seed), 300);
+            let lines = test_support::lines(&input);
+            let used: usize = lines
+                .iter()
+                .filter_map(|l| l.split_once(' ')?.0.parse::<usize>().ok())
+                .sum();
+            let parsed = Day07::parse(&lines).unwrap();
+            assert_eq!(Day07::part1(&parsed), small_dirs.into());
+            let freed = Day07::part2(&parsed).as_int().unwrap() as usize;
+            assert!(freed >= used - 40_000_000 && freed <= used);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let lines = test_support::lines("$ cd /\n$ ls\n14848514 b.txt\n$ cat b.txt\n");
+        let err = Day07::parse(&lines).err().unwrap();
+        let span = err.span.as_ref().unwrap();
+        assert_eq!((span.line, span.columns.clone()), (4, 2..11));
+        assert_eq!(err.expected, "`cd` or `ls`");
+        let err = parse_shell(0, "b.txt 14848514").err().unwrap();
+        assert_eq!(
+            (err.expected.as_str(), err.found.as_str()),
+            ("`dir` or a file size", "`b.txt`")
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use aoc::{Answer, ParseError, Solution};
+
+#[cfg(any(test, feature = "differential"))]
+pub mod reference;
+
+#[derive(Default)]
+pub struct Tree {
+    height: u8,
+    is_visible: bool,
+}
+
+impl Tree {
+    pub fn new(height: u8) -> Self {
+        Tree {
+            height,
+            is_visible: false,
+        }
+    }
+}
+
+pub struct Forest {
+    data: grid::Grid<Tree>,
+}
+
+impl Forest {
+    pub fn addr_of_pos(&self, idx: usize) -> (usize, usize) {
+        let num_cols = self.data.cols();
+        let col = idx % num_cols;
+        let row = idx / num_cols;
+        (col, row)
+    }
+    pub fn get_mut_at(&mut self, idx: usize) -> &mut Tree {
+        let addr = self.addr_of_pos(idx);
+        self.data.get_mut(addr.1, addr.0).expect("missing tree")
+    }
+    pub fn size(&self) -> usize {
+        let (x, y) = self.data.size();
+        x * y
+    }
+    pub fn neighbors(&self, (x, y): (usize, usize)) -> Vec<Option<&Tree>> {
+        let dirs: [(i32, i32); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+        dirs.map(|(dx, dy)| {
+            let x_ = (x as i32) + dx;
+            let y_ = (y as i32) + dy;
+            if x_ >= 0 && y_ >= 0 {
+                self.data.get(y_ as usize, x_ as usize)
+            } else {
+                None
+            }
+        })
+        .into_iter()
+        .filter(|x| x.is_some())
+        .collect()
+    }
+    /// Row-major indices of the trees visible from outside the grid. Sweeps
+    /// every row and column from both ends, tracking the tallest tree so far.
+    pub fn trace_visible(&self) -> Vec<usize> {
+        let (rows, cols) = (self.data.rows(), self.data.cols());
+        let mut visible = vec![false; rows * cols];
+        let mut sweep = |cells: &mut dyn Iterator<Item = (usize, usize)>| {
+            let mut tallest: Option<u8> = None;
+            for (row, col) in cells {
+                let height = self.data.get(row, col).expect("missing tree").height;
+                if tallest.is_none_or(|t| height > t) {
+                    visible[row * cols + col] = true;
+                    tallest = Some(height);
+                }
+            }
+        };
+        for row in 0..rows {
+            sweep(&mut (0..cols).map(|col| (row, col)));
+            sweep(&mut (0..cols).rev().map(|col| (row, col)));
+        }
+        for col in 0..cols {
+            sweep(&mut (0..rows).map(|row| (row, col)));
+            sweep(&mut (0..rows).rev().map(|row| (row, col)));
+        }
+        (0..rows * cols).filter(|&i| visible[i]).collect()
+    }
+
+    pub fn visible(&self) -> Vec<&Tree> {
+        self.data.iter().filter(|x| x.is_visible).collect()
+    }
+
+    pub fn cell(&self, x: i32, y: i32) -> Option<&Tree> {
+        if x < 0 || y < 0 {
+            None
+        } else {
+            self.data.get(y as usize, x as usize)
+        }
+    }
+
+    pub fn scenic_score_dir(&self, (dx, dy): (i32, i32), ith: usize) -> usize {
+        let (x0, y0) = self.addr_of_pos(ith);
+        let tree0 = self.data.get(y0, x0).unwrap();
+        let mut x = x0 as i32;
+        let mut y = y0 as i32;
+        let mut total = 0;
+        loop {
+            x += dx;
+            y += dy;
+            match self.cell(x, y) {
+                None => break,
+                Some(curr) => {
+                    total += 1;
+                    if curr.height >= tree0.height {
+                        break;
+                    }
+                }
+            }
+        }
+        total
+    }
+
+    pub fn scenic_score(&self, i: usize) -> usize {
+        [(0, -1), (0, 1), (-1, 0), (1, 0)]
+            .iter()
+            .map(|&dir| self.scenic_score_dir(dir, i))
+            .product()
+    }
+
+    pub fn max_scenic_score(&self) -> usize {
+        (0..self.size())
+            .map(|i| self.scenic_score(i))
+            .max()
+            .unwrap()
+    }
+}
+
+impl TryFrom<Vec<String>> for Forest {
+    type Error = ParseError;
+
+    fn try_from(lines: Vec<String>) -> Result<Self, ParseError> {
+        let cols = match lines.first() {
+            Some(first) => first.chars().count(),
+            None => return Err(ParseError::new("a grid of tree heights", "no rows")),
+        };
+        let mut data = grid::Grid::new(0, cols);
+        for (row_idx, line) in lines.iter().enumerate() {
+            let row = line
+                .char_indices()
+                .map(|(at, c)| match c.to_digit(10) {
+                    Some(height) => Ok(Tree::new(height as u8)),
+                    None => Err(ParseError::at(
+                        row_idx,
+                        line,
+                        at..at + c.len_utf8(),
+                        "a tree height from 0 to 9",
+                    )),
+                })
+                .collect::<Result<Vec<Tree>, ParseError>>()?;
+            if row.len() != cols {
+                let err = ParseError::at(
+                    row_idx,
+                    line,
+                    0..line.len(),
+                    format!("a row of {} trees", cols),
+                );
+                return Err(err.found(format!("{} trees", row.len())));
+            }
+            data.insert_row(row_idx, row);
+        }
+        let mut f = Forest { data };
+        for i in f.trace_visible() {
+            f.get_mut_at(i).is_visible = true;
+        }
+        Ok(f)
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Parsed = Forest;
+
+    fn parse(lines: &[String]) -> Result<Self::Parsed, ParseError> {
+        lines.to_vec().try_into()
+    }
+
+    fn part1(forest: &Self::Parsed) -> Answer {
+        forest.visible().len().into()
+    }
+
+    fn part2(forest: &Self::Parsed) -> Answer {
+        forest.max_scenic_score().into()
+    }
+}
+
+#[cfg(test)]
+mod test_day_08 {
+    use crate::*;
+
+    aoc::answer_tests!(Day08);
+
+    aoc::demo_test!(test_demo_input_p1, Day08, part1, "input_p1_demo.txt", 21);
+    aoc::demo_test!(test_demo_input_p2, Day08, part2, "input_p1_demo.txt", 8);
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let size = 5 + seed as usize * 7;
+            let input = test_support::day_08::generate(&mut test_support::rng(seed), size, size);
+            let parsed = Day08::parse(&test_support::lines(&input)).unwrap();
+            let visible = Day08::part1(&parsed).as_int().unwrap() as usize;
+            assert!(visible >= 4 * size - 4 && visible <= size * size);
+            // An interior tree sees at least one tree each way, and opposite
+            // viewing distances add up to at most size - 1.
+            let score = Day08::part2(&parsed).as_int().unwrap();
+            let half = ((size - 1) * (size - 1) / 4) as i128;
+            assert!(score >= 1 && score <= half * half);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Day08::parse(&test_support::lines("303\n2x5\n"))
+            .err()
+            .unwrap();
+        assert_eq!(err.found, "`x`");
+        assert!(err
+            .to_string()
+            .ends_with("2 | 2x5\n  |  ^ a tree height from 0 to 9"));
+        let err = Day08::parse(&test_support::lines("303\n25\n"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            (err.expected.as_str(), err.found.as_str()),
+            ("a row of 3 trees", "2 trees")
+        );
+        assert!(Day08::parse(&[]).is_err());
+    }
+
+    #[cfg(feature = "differential")]
+    #[test]
+    fn test_differential_against_reference() {
+        use test_support::differential::{assert_agree, shrink_square};
+        let grids = (0..100).map(|seed| {
+            let size = 1 + seed as usize % 25;
+            let input = test_support::day_08::generate(&mut test_support::rng(seed), size, size);
+            test_support::lines(&input)
+        });
+        assert_agree(
+            grids,
+            |lines| reference::visible(&Forest::try_from(lines.clone()).unwrap()),
+            |lines| Forest::try_from(lines.clone()).unwrap().trace_visible(),
+            |lines| shrink_square(lines),
+        );
+    }
+}
@@ -0,0 +1,56 @@
+//! The original visibility scan, kept as an oracle for the optimised one.
+//! It reads the grid transposed, so compare square grids through
+//! [`visible`], which maps its indices back.
+
+use std::ops::Range;
+
+use crate::{Forest, Tree};
+
+pub fn trace_visible(forest: &Forest) -> Vec<usize> {
+    let size = forest.size();
+    (0..size)
+        .filter(|&i| {
+            let addr = { forest.addr_of_pos(i) };
+            let (x, y) = addr;
+            let tree = forest
+                .data
+                .get(x, y)
+                .unwrap_or_else(|| panic!("missing tree @ {},{}", x, y));
+            let neighbors: Vec<&Tree> =
+                forest.neighbors((x, y)).iter().filter_map(|x| *x).collect();
+            if neighbors.len() < 4 {
+                true // boundary
+            } else {
+                let y1 = || (0..y).all(|yi| forest.data.get(x, yi).unwrap().height < tree.height);
+                let y2 = || {
+                    (Range {
+                        start: y + 1,
+                        end: forest.data.rows(),
+                    })
+                    .all(|yi| forest.data.get(x, yi).unwrap().height < tree.height)
+                };
+                let x1 = || (0..x).all(|xi| forest.data.get(xi, y).unwrap().height < tree.height);
+                let x2 = || {
+                    (Range {
+                        start: x + 1,
+                        end: forest.data.cols(),
+                    })
+                    .all(|xi| forest.data.get(xi, y).unwrap().height < tree.height)
+                };
+                y1() || y2() || x1() || x2()
+            }
+        })
+        .collect::<Vec<usize>>()
+}
+
+/// [`trace_visible`]'s indices transposed back to row-major order, sorted.
+/// Only meaningful for square grids.
+pub fn visible(forest: &Forest) -> Vec<usize> {
+    let cols = forest.data.cols();
+    let mut visible: Vec<usize> = trace_visible(forest)
+        .into_iter()
+        .map(|i| (i % cols) * cols + i / cols)
+        .collect();
+    visible.sort_unstable();
+    visible
+}
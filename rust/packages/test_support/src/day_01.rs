@@ -0,0 +1,29 @@
+use crate::{Rng, StdRng};
+
+/// `elves` blank-line-separated blocks of one to ten calorie counts.
+pub fn generate(rng: &mut StdRng, elves: usize) -> String {
+    let blocks: Vec<String> = (0..elves)
+        .map(|_| {
+            let items = rng.gen_range(1..=10);
+            (0..items)
+                .map(|_| format!("{}\n", rng.gen_range(1000..=60000)))
+                .collect()
+        })
+        .collect();
+    blocks.join("\n")
+}
+
+#[cfg(test)]
+mod test_day_01 {
+    use crate::*;
+
+    #[test]
+    fn test_blocks() {
+        let input = day_01::generate(&mut rng(1), 50);
+        assert_eq!(input.split("\n\n").count(), 50);
+        assert!(lines(&input)
+            .iter()
+            .all(|l| l.is_empty() || l.parse::<usize>().is_ok()));
+        assert_eq!(input, day_01::generate(&mut rng(1), 50));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{Rng, StdRng};
+
+/// `rounds` lines of `A X` style strategy guide entries.
+pub fn generate(rng: &mut StdRng, rounds: usize) -> String {
+    (0..rounds)
+        .map(|_| {
+            let them = ['A', 'B', 'C'][rng.gen_range(0..3)];
+            let us = ['X', 'Y', 'Z'][rng.gen_range(0..3)];
+            format!("{} {}\n", them, us)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_day_02 {
+    use crate::*;
+
+    #[test]
+    fn test_rounds() {
+        let input = day_02::generate(&mut rng(2), 100);
+        let rounds = lines(&input);
+        assert_eq!(rounds.len(), 100);
+        assert!(rounds
+            .iter()
+            .all(|r| r.len() == 3 && "ABC".contains(&r[..1]) && "XYZ".contains(&r[2..])));
+    }
+}
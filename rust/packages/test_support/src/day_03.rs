@@ -0,0 +1,75 @@
+use rand::seq::SliceRandom;
+
+use crate::{Rng, StdRng};
+
+/// Rucksacks in groups of three, with the item planted in both compartments
+/// of each sack and each group's badge.
+pub struct Rucksacks {
+    pub input: String,
+    pub shared: Vec<char>,
+    pub badges: Vec<char>,
+}
+
+fn items() -> Vec<char> {
+    ('a'..='z').chain('A'..='Z').collect()
+}
+
+/// Every sack has exactly one item type in both compartments, and every
+/// group of three sacks has exactly one item type in common.
+pub fn generate(rng: &mut StdRng, groups: usize) -> Rucksacks {
+    let mut out = Rucksacks {
+        input: String::new(),
+        shared: vec![],
+        badges: vec![],
+    };
+    for _ in 0..groups {
+        let mut pool = items();
+        pool.shuffle(rng);
+        let badge = pool.pop().unwrap();
+        out.badges.push(badge);
+        // 51 items left: one shared item and 16 compartment-only items per sack.
+        for sack in pool.chunks(17) {
+            let (shared, rest) = sack.split_first().unwrap();
+            let (left_only, right_only) = rest.split_at(8);
+            let half = rng.gen_range(2..=12);
+            let mut left = vec![*shared, badge];
+            let mut right = vec![*shared];
+            left.extend((left.len()..half).map(|_| *left_only.choose(rng).unwrap()));
+            right.extend((right.len()..half).map(|_| *right_only.choose(rng).unwrap()));
+            left.shuffle(rng);
+            right.shuffle(rng);
+            out.input.extend(left.iter().chain(&right));
+            out.input.push('\n');
+            out.shared.push(*shared);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test_day_03 {
+    use std::collections::HashSet;
+
+    use crate::*;
+
+    #[test]
+    fn test_planted_items() {
+        let sacks = day_03::generate(&mut rng(3), 20);
+        let lines = lines(&sacks.input);
+        assert_eq!(lines.len(), 60);
+        for (line, shared) in lines.iter().zip(&sacks.shared) {
+            let (l, r) = line.split_at(line.len() / 2);
+            let l: HashSet<char> = l.chars().collect();
+            let common: Vec<char> = r.chars().filter(|c| l.contains(c)).collect();
+            assert!(!common.is_empty() && common.iter().all(|c| c == shared));
+        }
+        for (group, badge) in lines.chunks(3).zip(&sacks.badges) {
+            let sets: Vec<HashSet<char>> = group.iter().map(|l| l.chars().collect()).collect();
+            let common: Vec<&char> = sets[0]
+                .iter()
+                .filter(|c| sets[1].contains(c) && sets[2].contains(c))
+                .collect();
+            assert_eq!(common, vec![badge]);
+        }
+    }
+}
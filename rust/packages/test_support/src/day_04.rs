@@ -0,0 +1,31 @@
+use crate::{Rng, StdRng};
+
+fn range(rng: &mut StdRng) -> (u8, u8) {
+    let start = rng.gen_range(1..=99);
+    (start, rng.gen_range(start..=99))
+}
+
+/// `pairs` lines of `a-b,c-d` section assignments within 1..=99.
+pub fn generate(rng: &mut StdRng, pairs: usize) -> String {
+    (0..pairs)
+        .map(|_| {
+            let (a, b) = range(rng);
+            let (c, d) = range(rng);
+            format!("{}-{},{}-{}\n", a, b, c, d)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_day_04 {
+    use crate::*;
+
+    #[test]
+    fn test_ranges_are_ordered() {
+        let input = day_04::generate(&mut rng(4), 200);
+        for line in lines(&input) {
+            let n: Vec<u8> = line.split([',', '-']).map(|n| n.parse().unwrap()).collect();
+            assert!(n[0] <= n[1] && n[2] <= n[3] && n[3] <= 99);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{Rng, StdRng};
+
+fn drawing(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut out = String::new();
+    for row in (0..height).rev() {
+        let cells: Vec<String> = stacks
+            .iter()
+            .map(|s| match s.get(row) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .collect();
+        out += cells.join(" ").trim_end();
+        out.push('\n');
+    }
+    let labels: Vec<String> = (1..=stacks.len()).map(|i| format!(" {} ", i)).collect();
+    out += labels.join(" ").trim_end();
+    out.push('\n');
+    out
+}
+
+/// A drawing of `stacks` (2 to 9) crate stacks, then `moves` (at least one)
+/// moves that are all legal when applied one crate at a time or all at once.
+pub fn generate(rng: &mut StdRng, stacks: usize, moves: usize) -> String {
+    assert!(
+        (2..=9).contains(&stacks),
+        "moves need two stacks and stack labels are single digits"
+    );
+    assert!(moves > 0, "an input without moves has no moves section");
+    let mut crates: Vec<Vec<char>> = (0..stacks)
+        .map(|_| {
+            (0..rng.gen_range(0..=8))
+                .map(|_| rng.gen_range('A'..='Z'))
+                .collect()
+        })
+        .collect();
+    if crates.iter().all(Vec::is_empty) {
+        crates[0].push(rng.gen_range('A'..='Z'));
+    }
+    let mut out = drawing(&crates);
+    out.push('\n');
+    // Only heights matter for legality, so track those.
+    let mut heights: Vec<usize> = crates.iter().map(Vec::len).collect();
+    for _ in 0..moves {
+        let from = loop {
+            let i = rng.gen_range(0..stacks);
+            if heights[i] > 0 {
+                break i;
+            }
+        };
+        let to = (from + rng.gen_range(1..stacks)) % stacks;
+        let n = rng.gen_range(1..=heights[from]);
+        heights[from] -= n;
+        heights[to] += n;
+        out += &format!("move {} from {} to {}\n", n, from + 1, to + 1);
+    }
+    out
+}
+
+#[cfg(test)]
+mod test_day_05 {
+    use crate::*;
+
+    #[test]
+    fn test_drawing_and_moves() {
+        let input = day_05::generate(&mut rng(5), 3, 20);
+        let (drawing, moves) = input.split_once("\n\n").unwrap();
+        assert!(drawing.ends_with(" 1   2   3"));
+        assert!(drawing.lines().all(|l| l.len() <= 11 && l == l.trim_end()));
+        assert_eq!(lines(moves).len(), 20);
+        assert!(lines(moves).iter().all(|m| m.starts_with("move ")));
+    }
+}
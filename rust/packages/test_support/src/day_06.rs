@@ -0,0 +1,62 @@
+use rand::seq::SliceRandom;
+
+use crate::{Rng, StdRng};
+
+/// A datastream with a planted start-of-packet and start-of-message marker.
+/// `packet` and `message` count the characters processed once each marker is
+/// complete.
+pub struct Signal {
+    pub input: String,
+    pub packet: usize,
+    pub message: usize,
+}
+
+/// A `len` character stream (at least 17). The prefix only uses `a`-`c`,
+/// so no marker can end in it, and ends in `aaa`; fourteen distinct letters
+/// from `d`-`z` follow, after which the stream is random.
+pub fn generate(rng: &mut StdRng, len: usize) -> Signal {
+    assert!(
+        len >= 17,
+        "a stream needs room for `aaa` and a message marker"
+    );
+    let prefix = rng.gen_range(3..=len - 14);
+    let mut input: String = (0..prefix - 3).map(|_| rng.gen_range('a'..='c')).collect();
+    input += "aaa";
+    let mut marker: Vec<char> = ('d'..='z').collect();
+    marker.shuffle(rng);
+    input.extend(&marker[..14]);
+    input.extend((prefix + 14..len).map(|_| rng.gen_range('a'..='z')));
+    input.push('\n');
+    Signal {
+        input,
+        packet: prefix + 3,
+        message: prefix + 13,
+    }
+}
+
+#[cfg(test)]
+mod test_day_06 {
+    use std::collections::HashSet;
+
+    use crate::*;
+
+    fn first_marker(s: &str, n: usize) -> usize {
+        let chars: Vec<char> = s.chars().collect();
+        chars
+            .windows(n)
+            .position(|w| w.iter().collect::<HashSet<_>>().len() == n)
+            .unwrap()
+            + n
+    }
+
+    #[test]
+    fn test_planted_markers() {
+        for seed in 0..20 {
+            let signal = day_06::generate(&mut rng(seed), 17 + seed as usize * 10);
+            let stream = signal.input.trim_end();
+            assert_eq!(stream.len(), 17 + seed as usize * 10);
+            assert_eq!(first_marker(stream, 4), signal.packet);
+            assert_eq!(first_marker(stream, 14), signal.message);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{Rng, StdRng};
+
+struct Dir {
+    files: Vec<(String, usize)>,
+    dirs: Vec<(String, Dir)>,
+}
+
+fn name(rng: &mut StdRng, taken: &[&str]) -> String {
+    loop {
+        let len = rng.gen_range(1..=8);
+        let name: String = (0..len).map(|_| rng.gen_range('a'..='z')).collect();
+        if !taken.contains(&name.as_str()) {
+            return name;
+        }
+    }
+}
+
+fn build(rng: &mut StdRng, budget: &mut usize, max_file: usize, depth: usize) -> Dir {
+    let mut dir = Dir {
+        files: vec![],
+        dirs: vec![],
+    };
+    let mut taken: Vec<String> = vec![];
+    for _ in 0..rng.gen_range(1..=6) {
+        if *budget == 0 {
+            break;
+        }
+        *budget -= 1;
+        let taken_refs: Vec<&str> = taken.iter().map(String::as_str).collect();
+        let entry = name(rng, &taken_refs);
+        taken.push(entry.clone());
+        if *budget > 0 && depth < 8 && rng.gen_bool(0.35) {
+            let sub = build(rng, budget, max_file, depth + 1);
+            dir.dirs.push((entry, sub));
+        } else {
+            dir.files.push((entry, rng.gen_range(1..=max_file)));
+        }
+    }
+    dir
+}
+
+fn size(dir: &Dir) -> usize {
+    dir.files.iter().map(|(_, s)| s).sum::<usize>()
+        + dir.dirs.iter().map(|(_, d)| size(d)).sum::<usize>()
+}
+
+fn transcript(dir: &Dir, out: &mut String) {
+    *out += "$ ls\n";
+    for (name, _) in &dir.dirs {
+        *out += &format!("dir {}\n", name);
+    }
+    for (name, size) in &dir.files {
+        *out += &format!("{} {}\n", size, name);
+    }
+    for (name, sub) in &dir.dirs {
+        *out += &format!("$ cd {}\n", name);
+        transcript(sub, out);
+        *out += "$ cd ..\n";
+    }
+}
+
+/// A depth-first `cd`/`ls` session over a random tree of about `entries`
+/// files and directories. Like real inputs, the disk is 40-60M full, so
+/// there is always a directory worth deleting for part 2.
+pub fn generate(rng: &mut StdRng, entries: usize) -> String {
+    generate_with_total(rng, entries).0
+}
+
+/// Like [`generate`], along with the part 1 answer: the summed sizes of
+/// every directory of at most 100000.
+pub fn generate_with_total(rng: &mut StdRng, entries: usize) -> (String, usize) {
+    let mut budget = entries.max(1);
+    let max_file = (25_000_000 / budget).clamp(1, 300_000);
+    let mut root = build(rng, &mut budget, max_file, 0);
+    let used = size(&root);
+    let target = rng.gen_range(40_000_001..=60_000_000);
+    let taken: Vec<&str> = root.files.iter().map(|(n, _)| n.as_str()).collect();
+    let filler = format!("{}.dat", name(rng, &taken));
+    root.files.push((filler, target - used));
+    let mut out = "$ cd /\n".to_string();
+    transcript(&root, &mut out);
+    (out, small_total(&root))
+}
+
+fn small_total(dir: &Dir) -> usize {
+    let own = match size(dir) {
+        s if s <= 100_000 => s,
+        _ => 0,
+    };
+    own + dir.dirs.iter().map(|(_, d)| small_total(d)).sum::<usize>()
+}
+
+#[cfg(test)]
+mod test_day_07 {
+    use crate::*;
+
+    #[test]
+    fn test_transcript_is_consistent() {
+        let input = day_07::generate(&mut rng(7), 200);
+        let mut depth = 0usize;
+        let mut total = 0;
+        for line in lines(&input).iter().skip(1) {
+            match line.split_once(' ').unwrap() {
+                ("$", "cd ..") => depth -= 1,
+                ("$", "ls") | ("dir", _) => {}
+                ("$", _) => depth += 1,
+                (size, _) => total += size.parse::<usize>().unwrap(),
+            }
+        }
+        assert_eq!(depth, 0);
+        assert!((40_000_001..=60_000_000).contains(&total));
+        let (text, small) = day_07::generate_with_total(&mut rng(7), 200);
+        assert_eq!(text, input);
+        assert!(small > 0);
+    }
+}
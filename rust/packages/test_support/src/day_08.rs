@@ -0,0 +1,27 @@
+use crate::{Rng, StdRng};
+
+/// A `width` by `height` grid of tree heights 0-9.
+pub fn generate(rng: &mut StdRng, width: usize, height: usize) -> String {
+    (0..height)
+        .map(|_| {
+            let mut row: String = (0..width).map(|_| rng.gen_range('0'..='9')).collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_day_08 {
+    use crate::*;
+
+    #[test]
+    fn test_grid_shape() {
+        let input = day_08::generate(&mut rng(8), 7, 4);
+        let rows = lines(&input);
+        assert_eq!(rows.len(), 4);
+        assert!(rows
+            .iter()
+            .all(|r| r.len() == 7 && r.bytes().all(|b| b.is_ascii_digit())));
+    }
+}
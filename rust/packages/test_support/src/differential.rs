@@ -0,0 +1,137 @@
+use std::fmt::Debug;
+
+/// An input on which a candidate solver disagrees with its reference, shrunk
+/// as far as it would go while still disagreeing.
+#[derive(Debug)]
+pub struct Disagreement<I, O> {
+    pub original: I,
+    pub input: I,
+    pub reference: O,
+    pub candidate: O,
+    pub shrinks: usize,
+}
+
+/// Runs both solvers on every input and, on the first disagreement, keeps
+/// taking the first smaller input from `shrink` that still disagrees.
+pub fn find_disagreement<I: Clone, O: PartialEq>(
+    inputs: impl IntoIterator<Item = I>,
+    reference: impl Fn(&I) -> O,
+    candidate: impl Fn(&I) -> O,
+    shrink: impl Fn(&I) -> Vec<I>,
+) -> Option<Disagreement<I, O>> {
+    let disagrees = |input: &I| {
+        let (r, c) = (reference(input), candidate(input));
+        (r != c).then_some((r, c))
+    };
+    let (original, (mut r, mut c)) = inputs
+        .into_iter()
+        .find_map(|input| disagrees(&input).map(|d| (input, d)))?;
+    let mut input = original.clone();
+    let mut shrinks = 0;
+    while let Some((smaller, d)) = shrink(&input)
+        .into_iter()
+        .find_map(|s| disagrees(&s).map(|d| (s, d)))
+    {
+        input = smaller;
+        (r, c) = d;
+        shrinks += 1;
+    }
+    Some(Disagreement {
+        original,
+        input,
+        reference: r,
+        candidate: c,
+        shrinks,
+    })
+}
+
+/// [`find_disagreement`], panicking with the minimal input if there is one.
+#[track_caller]
+pub fn assert_agree<I: Clone + Debug, O: PartialEq + Debug>(
+    inputs: impl IntoIterator<Item = I>,
+    reference: impl Fn(&I) -> O,
+    candidate: impl Fn(&I) -> O,
+    shrink: impl Fn(&I) -> Vec<I>,
+) {
+    if let Some(d) = find_disagreement(inputs, reference, candidate, shrink) {
+        panic!(
+            "solvers disagree on {:?} (shrunk {} times)\n reference: {:?}\n candidate: {:?}",
+            d.input, d.shrinks, d.reference, d.candidate
+        );
+    }
+}
+
+/// Copies of `s` with a run of characters removed, largest runs first.
+pub fn shrink_text(s: &str) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = vec![];
+    let mut run = chars.len() / 2;
+    while run > 0 {
+        for start in (0..=chars.len() - run).step_by(run) {
+            out.push(chars[..start].iter().chain(&chars[start + run..]).collect());
+        }
+        run /= 2;
+    }
+    if chars.len() == 1 {
+        out.push(String::new());
+    }
+    out
+}
+
+/// Copies of a square grid with one row and the matching column removed.
+pub fn shrink_square(rows: &[String]) -> Vec<Vec<String>> {
+    if rows.len() <= 1 {
+        return vec![];
+    }
+    (0..rows.len())
+        .map(|i| {
+            rows.iter()
+                .enumerate()
+                .filter(|&(r, _)| r != i)
+                .map(|(_, row)| {
+                    row.chars()
+                        .enumerate()
+                        .filter(|&(c, _)| c != i)
+                        .map(|(_, ch)| ch)
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_differential {
+    use crate::differential::*;
+
+    #[test]
+    fn test_shrinks_to_minimal_input() {
+        let inputs = ["abc", "quixotic sync", "zzz"].map(String::from);
+        let d = find_disagreement(
+            inputs,
+            |s: &String| s.len(),
+            |s: &String| s.len() + usize::from(s.contains('x') && s.contains('y')),
+            |s| shrink_text(s),
+        )
+        .unwrap();
+        assert_eq!(d.original, "quixotic sync");
+        assert_eq!(d.input, "xy");
+        assert_eq!((d.reference, d.candidate), (2, 3));
+        assert!(find_disagreement(
+            ["ok".to_string()],
+            |s| s.len(),
+            |s| s.len(),
+            |s| shrink_text(s)
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_shrink_square() {
+        let grid = vec!["123".to_string(), "456".to_string(), "789".to_string()];
+        let smaller = shrink_square(&grid);
+        assert_eq!(smaller.len(), 3);
+        assert_eq!(smaller[1], vec!["13", "79"]);
+        assert!(shrink_square(&["1".to_string()]).is_empty());
+    }
+}
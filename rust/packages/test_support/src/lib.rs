@@ -0,0 +1,30 @@
+//! Seeded generators for valid puzzle inputs of any size, one module per
+//! day. Every generator returns the input's text, newline-terminated like a
+//! downloaded input, and is deterministic for a given seed.
+
+use rand::SeedableRng;
+
+pub use rand::{rngs::StdRng, Rng};
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod differential;
+
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// Splits generated text into lines the way `aoc::try_lines` does.
+pub fn lines(input: &str) -> Vec<String> {
+    let mut lines: Vec<String> = input.split('\n').map(String::from).collect();
+    while lines.last().is_some_and(String::is_empty) {
+        lines.pop();
+    }
+    lines
+}